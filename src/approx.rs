@@ -0,0 +1,404 @@
+#[cfg(feature = "nightly")]
+use core::alloc::Allocator;
+
+use core::fmt::Debug;
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+#[cfg(not(feature = "nightly"))]
+use crate::alloc::Allocator;
+use crate::array::Array;
+use crate::expr::IntoExpression;
+use crate::layout::Layout;
+use crate::shape::{ConstShape, Shape};
+use crate::slice::Slice;
+use crate::tensor::Tensor;
+use crate::view::{View, ViewMut};
+
+/// Asserts that two arrays are equal using `AbsDiffEq`.
+///
+/// The arrays must have the same shape, and the epsilon is optional. On failure,
+/// the index of the first differing element is included in the panic message.
+///
+/// # Examples
+///
+/// ```
+/// use mdarray::{assert_array_abs_diff_eq, tensor};
+///
+/// let a = tensor![[1.0, 2.0], [3.0, 4.0]];
+/// let b = tensor![[1.0, 2.0], [3.0, 4.0 + 1e-10]];
+///
+/// assert_array_abs_diff_eq!(a, b, epsilon = 1e-9);
+/// ```
+#[macro_export]
+macro_rules! assert_array_abs_diff_eq {
+    ($left:expr, $right:expr $(, epsilon = $epsilon:expr)? $(,)?) => {
+        $crate::__assert_abs_diff_eq(&$left, &$right, None $(.or(Some($epsilon)))?)
+    };
+}
+
+/// Asserts that two arrays are equal using `RelativeEq`.
+///
+/// The arrays must have the same shape, and the epsilon and maximum relative
+/// difference are optional. On failure, the index of the first differing element
+/// is included in the panic message.
+///
+/// # Examples
+///
+/// ```
+/// use mdarray::{assert_array_relative_eq, tensor};
+///
+/// let a = tensor![[1.0, 2.0], [3.0, 4.0]];
+/// let b = tensor![[1.0, 2.0], [3.0, 4.0 + 1e-10]];
+///
+/// assert_array_relative_eq!(a, b, max_relative = 1e-9);
+/// ```
+#[macro_export]
+macro_rules! assert_array_relative_eq {
+    (
+        $left:expr,
+        $right:expr
+        $(, epsilon = $epsilon:expr)?
+        $(, max_relative = $max_relative:expr)?
+        $(,)?
+    ) => {
+        $crate::__assert_relative_eq(
+            &$left,
+            &$right,
+            None $(.or(Some($epsilon)))?,
+            None $(.or(Some($max_relative)))?,
+        )
+    };
+}
+
+/// Asserts that two arrays are equal using `UlpsEq`.
+///
+/// The arrays must have the same shape, and the epsilon and maximum number of units
+/// in the last place are optional. On failure, the index of the first differing
+/// element is included in the panic message.
+///
+/// # Examples
+///
+/// ```
+/// use mdarray::{assert_array_ulps_eq, tensor};
+///
+/// let a = tensor![[1.0, 2.0], [3.0, 4.0]];
+/// let b = tensor![[1.0, 2.0], [3.0, 4.0 + 1e-15]];
+///
+/// assert_array_ulps_eq!(a, b, max_ulps = 4);
+/// ```
+#[macro_export]
+macro_rules! assert_array_ulps_eq {
+    (
+        $left:expr,
+        $right:expr
+        $(, epsilon = $epsilon:expr)?
+        $(, max_ulps = $max_ulps:expr)?
+        $(,)?
+    ) => {
+        $crate::__assert_ulps_eq(
+            &$left,
+            &$right,
+            None $(.or(Some($epsilon)))?,
+            None $(.or(Some($max_ulps)))?,
+        )
+    };
+}
+
+impl<T, S: Shape, L: Layout> Slice<T, S, L> {
+    /// Returns the index of the first element that is not equal to the corresponding
+    /// element of another array using `AbsDiffEq`, or `None` if all elements are equal.
+    ///
+    /// Note that the `approx` assertion macros do not report the index on failure, so
+    /// use `assert_array_abs_diff_eq!` instead to include it in the failure message.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shapes do not match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::tensor;
+    ///
+    /// let a = tensor![[1.0, 2.0], [3.0, 4.0]];
+    /// let b = tensor![[1.0, 2.0], [3.5, 4.0]];
+    ///
+    /// assert_eq!(a.first_abs_diff_ne(&b, 0.1), Some([1, 0]));
+    /// assert_eq!(a.first_abs_diff_ne(&b, 1.0), None);
+    /// ```
+    pub fn first_abs_diff_ne<U, R: Shape, K: Layout>(
+        &self,
+        other: &Slice<U, R, K>,
+        epsilon: T::Epsilon,
+    ) -> Option<S::Dims<usize>>
+    where
+        T: AbsDiffEq<U, Epsilon: Clone>,
+    {
+        assert!(same_shape(self, other), "shape mismatch");
+
+        position(self, other, |x, y| x.abs_diff_eq(y, epsilon.clone())).map(|(index, ..)| index)
+    }
+
+    /// Returns the index of the first element that is not equal to the corresponding
+    /// element of another array using `RelativeEq`, or `None` if all elements are equal.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shapes do not match.
+    pub fn first_relative_ne<U, R: Shape, K: Layout>(
+        &self,
+        other: &Slice<U, R, K>,
+        epsilon: T::Epsilon,
+        max_relative: T::Epsilon,
+    ) -> Option<S::Dims<usize>>
+    where
+        T: RelativeEq<U, Epsilon: Clone>,
+    {
+        assert!(same_shape(self, other), "shape mismatch");
+
+        position(self, other, |x, y| x.relative_eq(y, epsilon.clone(), max_relative.clone()))
+            .map(|(index, ..)| index)
+    }
+
+    /// Returns the index of the first element that is not equal to the corresponding
+    /// element of another array using `UlpsEq`, or `None` if all elements are equal.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shapes do not match.
+    pub fn first_ulps_ne<U, R: Shape, K: Layout>(
+        &self,
+        other: &Slice<U, R, K>,
+        epsilon: T::Epsilon,
+        max_ulps: u32,
+    ) -> Option<S::Dims<usize>>
+    where
+        T: UlpsEq<U, Epsilon: Clone>,
+    {
+        assert!(same_shape(self, other), "shape mismatch");
+
+        position(self, other, |x, y| x.ulps_eq(y, epsilon.clone(), max_ulps))
+            .map(|(index, ..)| index)
+    }
+}
+
+impl<T, U, S: Shape, R: Shape, L: Layout, K: Layout, I: ?Sized> AbsDiffEq<I> for Slice<T, S, L>
+where
+    for<'a> &'a I: IntoExpression<IntoExpr = View<'a, U, R, K>>,
+    T: AbsDiffEq<U, Epsilon: Clone>,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> T::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &I, epsilon: T::Epsilon) -> bool {
+        let other = other.into_expr();
+
+        same_shape(self, &other)
+            && position(self, &other, |x, y| x.abs_diff_eq(y, epsilon.clone())).is_none()
+    }
+}
+
+impl<T, U, S: Shape, R: Shape, L: Layout, K: Layout, I: ?Sized> RelativeEq<I> for Slice<T, S, L>
+where
+    for<'a> &'a I: IntoExpression<IntoExpr = View<'a, U, R, K>>,
+    T: RelativeEq<U, Epsilon: Clone>,
+{
+    fn default_max_relative() -> T::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &I, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+        let other = other.into_expr();
+
+        same_shape(self, &other)
+            && position(self, &other, |x, y| {
+                x.relative_eq(y, epsilon.clone(), max_relative.clone())
+            })
+            .is_none()
+    }
+}
+
+impl<T, U, S: Shape, R: Shape, L: Layout, K: Layout, I: ?Sized> UlpsEq<I> for Slice<T, S, L>
+where
+    for<'a> &'a I: IntoExpression<IntoExpr = View<'a, U, R, K>>,
+    T: UlpsEq<U, Epsilon: Clone>,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &I, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        let other = other.into_expr();
+
+        same_shape(self, &other)
+            && position(self, &other, |x, y| x.ulps_eq(y, epsilon.clone(), max_ulps)).is_none()
+    }
+}
+
+macro_rules! impl_approx {
+    (($($generics:tt)*), $type:ty) => {
+        impl<$($generics)*, U, R: Shape, K: Layout, I: ?Sized> AbsDiffEq<I> for $type
+        where
+            for<'a> &'a I: IntoExpression<IntoExpr = View<'a, U, R, K>>,
+            T: AbsDiffEq<U, Epsilon: Clone>,
+        {
+            type Epsilon = T::Epsilon;
+
+            fn default_epsilon() -> T::Epsilon {
+                T::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, other: &I, epsilon: T::Epsilon) -> bool {
+                (**self).abs_diff_eq(other, epsilon)
+            }
+        }
+
+        impl<$($generics)*, U, R: Shape, K: Layout, I: ?Sized> RelativeEq<I> for $type
+        where
+            for<'a> &'a I: IntoExpression<IntoExpr = View<'a, U, R, K>>,
+            T: RelativeEq<U, Epsilon: Clone>,
+        {
+            fn default_max_relative() -> T::Epsilon {
+                T::default_max_relative()
+            }
+
+            fn relative_eq(&self, other: &I, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+                (**self).relative_eq(other, epsilon, max_relative)
+            }
+        }
+
+        impl<$($generics)*, U, R: Shape, K: Layout, I: ?Sized> UlpsEq<I> for $type
+        where
+            for<'a> &'a I: IntoExpression<IntoExpr = View<'a, U, R, K>>,
+            T: UlpsEq<U, Epsilon: Clone>,
+        {
+            fn default_max_ulps() -> u32 {
+                T::default_max_ulps()
+            }
+
+            fn ulps_eq(&self, other: &I, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+                (**self).ulps_eq(other, epsilon, max_ulps)
+            }
+        }
+    };
+}
+
+impl_approx!((T, S: ConstShape), Array<T, S>);
+impl_approx!((T, S: Shape, A: Allocator), Tensor<T, S, A>);
+impl_approx!((T, S: Shape, L: Layout), View<'_, T, S, L>);
+impl_approx!((T, S: Shape, L: Layout), ViewMut<'_, T, S, L>);
+
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_abs_diff_eq<T, U, S: Shape, R: Shape, L: Layout, K: Layout>(
+    left: &Slice<T, S, L>,
+    right: &Slice<U, R, K>,
+    epsilon: Option<T::Epsilon>,
+) where
+    T: AbsDiffEq<U, Epsilon: Clone> + Debug,
+    U: Debug,
+{
+    let epsilon = epsilon.unwrap_or_else(T::default_epsilon);
+
+    assert_approx_eq(left, right, "abs_diff_eq", |x, y| x.abs_diff_eq(y, epsilon.clone()));
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_relative_eq<T, U, S: Shape, R: Shape, L: Layout, K: Layout>(
+    left: &Slice<T, S, L>,
+    right: &Slice<U, R, K>,
+    epsilon: Option<T::Epsilon>,
+    max_relative: Option<T::Epsilon>,
+) where
+    T: RelativeEq<U, Epsilon: Clone> + Debug,
+    U: Debug,
+{
+    let epsilon = epsilon.unwrap_or_else(T::default_epsilon);
+    let max_relative = max_relative.unwrap_or_else(T::default_max_relative);
+
+    assert_approx_eq(left, right, "relative_eq", |x, y| {
+        x.relative_eq(y, epsilon.clone(), max_relative.clone())
+    });
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_ulps_eq<T, U, S: Shape, R: Shape, L: Layout, K: Layout>(
+    left: &Slice<T, S, L>,
+    right: &Slice<U, R, K>,
+    epsilon: Option<T::Epsilon>,
+    max_ulps: Option<u32>,
+) where
+    T: UlpsEq<U, Epsilon: Clone> + Debug,
+    U: Debug,
+{
+    let epsilon = epsilon.unwrap_or_else(T::default_epsilon);
+    let max_ulps = max_ulps.unwrap_or_else(T::default_max_ulps);
+
+    assert_approx_eq(left, right, "ulps_eq", |x, y| x.ulps_eq(y, epsilon.clone(), max_ulps));
+}
+
+#[track_caller]
+fn assert_approx_eq<T: Debug, U: Debug, S: Shape, R: Shape, L: Layout, K: Layout, F>(
+    left: &Slice<T, S, L>,
+    right: &Slice<U, R, K>,
+    name: &str,
+    f: F,
+) where
+    F: FnMut(&T, &U) -> bool,
+{
+    if !same_shape(left, right) {
+        let (left, right) = (dims(left.shape()), dims(right.shape()));
+
+        panic!(
+            "assertion `left.{name}(right)` failed: shape mismatch\n  left: {left:?}\n right: {right:?}"
+        );
+    }
+
+    if let Some((index, x, y)) = position(left, right, f) {
+        panic!(
+            "assertion `left.{name}(right)` failed at index {index:?}\n  left: {x:?}\n right: {y:?}"
+        );
+    }
+}
+
+fn dims<S: Shape>(shape: &S) -> S::Dims<usize> {
+    let mut dims = shape.new_dims();
+
+    shape.with_dims(|src| dims.as_mut().copy_from_slice(src));
+
+    dims
+}
+
+// Returns the index and the elements for the first pair of elements that does not
+// satisfy the predicate, where the shapes must match.
+fn position<'a, 'b, T, U, S: Shape, R: Shape, L: Layout, K: Layout, F>(
+    this: &'a Slice<T, S, L>,
+    other: &'b Slice<U, R, K>,
+    mut f: F,
+) -> Option<(S::Dims<usize>, &'a T, &'b U)>
+where
+    F: FnMut(&T, &U) -> bool,
+{
+    let (mut offset, (x, y)) = this.iter().zip(other).enumerate().find(|(_, (x, y))| !f(x, y))?;
+    let mut index = this.shape().new_dims::<usize>();
+
+    for i in (0..this.rank()).rev() {
+        index.as_mut()[i] = offset % this.dim(i);
+        offset /= this.dim(i);
+    }
+
+    Some((index, x, y))
+}
+
+fn same_shape<T, U, S: Shape, R: Shape, L: Layout, K: Layout>(
+    this: &Slice<T, S, L>,
+    other: &Slice<U, R, K>,
+) -> bool {
+    this.shape().with_dims(|dims| other.shape().with_dims(|other| dims == other))
+}
@@ -5,6 +5,9 @@ use core::mem::{self, ManuallyDrop, MaybeUninit};
 use core::ops::{Deref, DerefMut, Index, IndexMut};
 use core::ptr;
 
+#[cfg(feature = "num-traits")]
+use num_traits::{Float, One, Zero};
+
 use crate::dim::Const;
 use crate::expr::{self, IntoExpr, Iter, Map, Zip};
 use crate::expr::{Apply, Expression, FromExpression, IntoExpression};
@@ -12,6 +15,8 @@ use crate::index::SliceIndex;
 use crate::layout::{Dense, Layout};
 use crate::shape::{ConstShape, Shape};
 use crate::slice::Slice;
+#[cfg(feature = "num-traits")]
+use crate::tensor;
 use crate::tensor::Tensor;
 use crate::traits::Owned;
 use crate::view::{View, ViewMut};
@@ -64,6 +69,15 @@ impl<T, S: ConstShape> Array<T, S> {
         self.apply(f)
     }
 
+    /// Creates an array with elements set to one.
+    #[cfg(feature = "num-traits")]
+    pub fn ones() -> Self
+    where
+        T: Clone + One,
+    {
+        Self::from_elem(T::one())
+    }
+
     /// Creates an array with uninitialized elements.
     pub fn uninit() -> Array<MaybeUninit<T>, S> {
         let array = <MaybeUninit<Self>>::uninit();
@@ -122,6 +136,29 @@ impl<T, S: ConstShape> Array<T, S> {
     }
 }
 
+#[cfg(feature = "num-traits")]
+impl<T, const N: usize> Array<T, (Const<N>,)> {
+    /// Creates an array with evenly spaced elements from `start` to `end`, where both
+    /// endpoints are included.
+    pub fn linspace(start: T, end: T) -> Self
+    where
+        T: Float,
+    {
+        Self::from_fn(tensor::linspace(start, end, N))
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl<T, const N: usize> Array<T, (Const<N>, Const<N>)> {
+    /// Creates a square array with ones on the diagonal and zeros elsewhere.
+    pub fn eye() -> Self
+    where
+        T: One + Zero,
+    {
+        Self::from_fn(|i| if i[0] == i[1] { T::one() } else { T::zero() })
+    }
+}
+
 impl<T, S: ConstShape> Array<MaybeUninit<T>, S> {
     /// Converts the array element type from `MaybeUninit<T>` to `T`.
     ///
@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::ArrowPrimitiveType;
+use arrow_array::{Array, FixedSizeListArray, PrimitiveArray};
+use arrow_buffer::{ArrowNativeType, ScalarBuffer};
+use arrow_schema::Field;
+
+use crate::dim::Dyn;
+use crate::error::Error;
+use crate::tensor::Tensor;
+
+impl<T: ArrowNativeType> Tensor<T, (Dyn, Dyn)> {
+    /// Converts the matrix into an Arrow fixed-size list array, where each row is a list.
+    ///
+    /// The elements are moved without copying. The Arrow data type is given by the type
+    /// parameter `A`, for example `Float64Type` for `f64`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of columns exceeds `i32::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrow_array::types::Int32Type;
+    /// use mdarray::{DTensor, tensor};
+    ///
+    /// let t = tensor![[1, 2, 3], [4, 5, 6]];
+    /// let a = t.clone().into_fixed_size_list::<Int32Type>();
+    ///
+    /// assert_eq!(a.value_length(), 3);
+    /// assert_eq!(DTensor::<i32, 2>::try_from_fixed_size_list::<Int32Type>(a), Ok(t));
+    /// ```
+    pub fn into_fixed_size_list<A>(self) -> FixedSizeListArray
+    where
+        A: ArrowPrimitiveType<Native = T>,
+    {
+        let (rows, cols) = *self.shape();
+        let size = i32::try_from(cols).expect("invalid size");
+
+        let values = PrimitiveArray::<A>::new(ScalarBuffer::from(self.into_vec()), None);
+        let field = Arc::new(Field::new_list_field(A::DATA_TYPE, false));
+
+        FixedSizeListArray::try_new_with_length(field, size, Arc::new(values), None, rows)
+            .expect("invalid array")
+    }
+
+    /// Tries to convert an Arrow fixed-size list array into a matrix, where each list
+    /// is a row.
+    ///
+    /// The elements are moved without copying if the values buffer is not shared and has
+    /// compatible alignment, and otherwise copied. Null values are not checked, and have
+    /// the contents of the values buffer.
+    ///
+    /// # Errors
+    ///
+    /// If the value type is not matching `A`, or if the length of the values is not
+    /// matching the list size, then an error is returned.
+    pub fn try_from_fixed_size_list<A>(array: FixedSizeListArray) -> Result<Self, Error>
+    where
+        A: ArrowPrimitiveType<Native = T>,
+    {
+        let rows = array.len();
+        let cols = array.value_length() as usize;
+
+        let (_, _, list_values, _) = array.into_parts();
+        let values = list_values.as_primitive_opt::<A>().ok_or(Error::Unsupported)?.clone();
+
+        // Release the reference to the values, so that the buffer can be reused.
+        drop(list_values);
+
+        if Some(values.len()) != rows.checked_mul(cols) {
+            return Err(Error::ShapeMismatch);
+        }
+
+        Ok(Tensor::from(values).into_shape([rows, cols]))
+    }
+}
+
+/// Converts an Arrow primitive array into a vector.
+///
+/// The elements are moved without copying if the values buffer is not shared and has
+/// compatible alignment, and otherwise copied. The null bitmap is ignored, so that null
+/// values have the contents of the values buffer. Check `null_count` before converting
+/// if the array can contain null values.
+impl<A: ArrowPrimitiveType> From<PrimitiveArray<A>> for Tensor<A::Native, (Dyn,)> {
+    fn from(array: PrimitiveArray<A>) -> Self {
+        let (_, values, _) = array.into_parts();
+
+        // Reuse the buffer if it is not shared and has the same layout as a vector.
+        let vec = match values.into_inner().into_vec() {
+            Ok(vec) => vec,
+            Err(buffer) => ScalarBuffer::<A::Native>::from(buffer).to_vec(),
+        };
+
+        Self::from(vec)
+    }
+}
+
+impl<A: ArrowPrimitiveType> From<Tensor<A::Native, (Dyn,)>> for PrimitiveArray<A> {
+    fn from(tensor: Tensor<A::Native, (Dyn,)>) -> Self {
+        Self::new(ScalarBuffer::from(tensor.into_vec()), None)
+    }
+}
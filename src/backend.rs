@@ -0,0 +1,559 @@
+//! Backend for BLAS and LAPACK operations on matrices and vectors.
+//!
+//! The `Backend` trait has required methods with raw pointers, dimensions and leading
+//! dimensions in the same form as the CBLAS and LAPACKE interfaces for row-major order,
+//! so that an implementation can forward directly to an external library. The provided
+//! methods take array slices with any layout. The strides are checked to select either
+//! `Transpose::NoTrans` or `Transpose::Trans`, and matrices with other layouts are
+//! copied to temporary arrays.
+//!
+//! The `Reference` backend is a pure-Rust implementation, which is available with the
+//! `num-traits` feature.
+
+#[cfg(feature = "num-traits")]
+use num_traits::Float;
+
+use crate::error::Error;
+#[cfg(feature = "num-traits")]
+use crate::expr::Expression;
+use crate::layout::Layout;
+#[cfg(feature = "num-traits")]
+use crate::layout::Strided;
+#[cfg(feature = "num-traits")]
+use crate::linalg;
+#[cfg(feature = "num-traits")]
+use crate::mapping::StridedMapping;
+#[cfg(feature = "num-traits")]
+use crate::matmul;
+#[cfg(feature = "num-traits")]
+use crate::shape::IntoShape;
+use crate::slice::DSlice;
+use crate::tensor::DTensor;
+#[cfg(feature = "num-traits")]
+use crate::tensor::Tensor;
+#[cfg(feature = "num-traits")]
+use crate::view::{DView, DViewMut, View, ViewMut};
+
+/// Transposition of a matrix operand.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Transpose {
+    /// The matrix is used as stored.
+    NoTrans,
+    /// The matrix is transposed.
+    Trans,
+}
+
+/// Backend trait for BLAS and LAPACK operations.
+///
+/// Matrices for the required methods are stored in row-major order with the leading
+/// dimension as the stride between rows, which must be at least the number of columns
+/// and at least one. Vectors are given as in BLAS with a pointer and a nonzero increment
+/// between elements. If the increment is negative, the pointer is to the element with
+/// the lowest address, which is the last element of the vector.
+///
+/// Pivot indices are zero-based, so that row `i` is interchanged with row `ipiv[i]`.
+pub trait Backend<T: Copy> {
+    /// Computes `y = alpha * x + y`.
+    ///
+    /// # Safety
+    ///
+    /// The pointers must be valid for `n` elements with the given increments, and
+    /// `y` must not overlap with `x`.
+    unsafe fn axpy_raw(&self, n: usize, alpha: T, x: *const T, incx: isize, y: *mut T, incy: isize);
+
+    /// Returns the dot product of `x` and `y`.
+    ///
+    /// # Safety
+    ///
+    /// The pointers must be valid for `n` elements with the given increments.
+    unsafe fn dot_raw(&self, n: usize, x: *const T, incx: isize, y: *const T, incy: isize) -> T;
+
+    /// Computes `c = alpha * op(a) * op(b) + beta * c`, where `op(a)` is `m x k` and
+    /// `op(b)` is `k x n`. If `beta` is zero, `c` is not read.
+    ///
+    /// # Safety
+    ///
+    /// The pointers must be valid for the matrices with the given leading dimensions,
+    /// and `c` must not overlap with `a` or `b`.
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn gemm_raw(
+        &self,
+        trans_a: Transpose,
+        trans_b: Transpose,
+        m: usize,
+        n: usize,
+        k: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        b: *const T,
+        ldb: usize,
+        beta: T,
+        c: *mut T,
+        ldc: usize,
+    );
+
+    /// Computes `y = alpha * op(a) * x + beta * y`, where `a` is stored as `m x n`.
+    /// If `beta` is zero, `y` is not read.
+    ///
+    /// # Safety
+    ///
+    /// The pointers must be valid for the matrix and vectors with the given leading
+    /// dimension and increments, and `y` must not overlap with `a` or `x`.
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn gemv_raw(
+        &self,
+        trans: Transpose,
+        m: usize,
+        n: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        x: *const T,
+        incx: isize,
+        beta: T,
+        y: *mut T,
+        incy: isize,
+    );
+
+    /// Computes the LU decomposition with partial pivoting of the `m x n` matrix `a`
+    /// in place, and stores the row interchanges in `ipiv`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Singular` if a pivot is zero, where the decomposition is still
+    /// completed.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be valid for the matrix with the given leading dimension, and
+    /// the length of `ipiv` must be the minimum of `m` and `n`.
+    unsafe fn getrf_raw(
+        &self,
+        m: usize,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        ipiv: &mut [usize],
+    ) -> Result<(), Error>;
+
+    /// Computes the Cholesky decomposition of the `n x n` matrix `a` in place, where
+    /// only the lower triangle is accessed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NotPositiveDefinite` if the matrix is not positive definite.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be valid for the matrix with the given leading dimension.
+    unsafe fn potrf_raw(&self, n: usize, a: *mut T, lda: usize) -> Result<(), Error>;
+
+    /// Computes `y = alpha * x + y`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector lengths do not match.
+    fn axpy<L: Layout, K: Layout>(&self, alpha: T, x: &DSlice<T, 1, L>, y: &mut DSlice<T, 1, K>) {
+        assert!(x.dim(0) == y.dim(0), "shape mismatch");
+
+        let Some((y_ptr, incy)) = raw_vector_mut(y) else {
+            let mut tmp = y.to_tensor();
+
+            self.axpy(alpha, x, &mut tmp);
+            y.assign(&tmp);
+
+            return;
+        };
+
+        let mut x_tmp = None;
+        let (x_ptr, incx) = raw_vector(x, &mut x_tmp);
+
+        unsafe { self.axpy_raw(x.dim(0), alpha, x_ptr, incx, y_ptr, incy) };
+    }
+
+    /// Returns the dot product of `x` and `y`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector lengths do not match.
+    fn dot<L: Layout, K: Layout>(&self, x: &DSlice<T, 1, L>, y: &DSlice<T, 1, K>) -> T {
+        assert!(x.dim(0) == y.dim(0), "shape mismatch");
+
+        let (mut x_tmp, mut y_tmp) = (None, None);
+        let (x_ptr, incx) = raw_vector(x, &mut x_tmp);
+        let (y_ptr, incy) = raw_vector(y, &mut y_tmp);
+
+        unsafe { self.dot_raw(x.dim(0), x_ptr, incx, y_ptr, incy) }
+    }
+
+    /// Computes `c = alpha * a * b + beta * c`. If `beta` is zero, `c` is not read.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix shapes do not match.
+    fn gemm<L: Layout, K: Layout, M: Layout>(
+        &self,
+        alpha: T,
+        a: &DSlice<T, 2, L>,
+        b: &DSlice<T, 2, K>,
+        beta: T,
+        c: &mut DSlice<T, 2, M>,
+    ) {
+        let (m, k, n) = (a.dim(0), a.dim(1), b.dim(1));
+
+        assert!(b.dim(0) == k && c.dim(0) == m && c.dim(1) == n, "shape mismatch");
+
+        let Some((trans_c, ldc)) = blas_layout(c) else {
+            let mut tmp = c.to_tensor();
+
+            self.gemm(alpha, a, b, beta, &mut tmp);
+            c.assign(&tmp);
+
+            return;
+        };
+
+        let (mut a_tmp, mut b_tmp) = (None, None);
+        let (trans_a, a, lda) = raw_matrix(a, &mut a_tmp);
+        let (trans_b, b, ldb) = raw_matrix(b, &mut b_tmp);
+
+        let c = c.as_mut_ptr();
+
+        match trans_c {
+            Transpose::NoTrans => unsafe {
+                self.gemm_raw(trans_a, trans_b, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc);
+            },
+            Transpose::Trans => unsafe {
+                // Compute the transpose of c, which is given by op(b)^T * op(a)^T.
+                let (trans_a, trans_b) = (flip(trans_a), flip(trans_b));
+
+                self.gemm_raw(trans_b, trans_a, n, m, k, alpha, b, ldb, a, lda, beta, c, ldc);
+            },
+        }
+    }
+
+    /// Computes `y = alpha * a * x + beta * y`. If `beta` is zero, `y` is not read.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix and vector shapes do not match.
+    fn gemv<L: Layout, K: Layout, M: Layout>(
+        &self,
+        alpha: T,
+        a: &DSlice<T, 2, L>,
+        x: &DSlice<T, 1, K>,
+        beta: T,
+        y: &mut DSlice<T, 1, M>,
+    ) {
+        let (m, n) = (a.dim(0), a.dim(1));
+
+        assert!(x.dim(0) == n && y.dim(0) == m, "shape mismatch");
+
+        let Some((y, incy)) = raw_vector_mut(y) else {
+            let mut tmp = y.to_tensor();
+
+            self.gemv(alpha, a, x, beta, &mut tmp);
+            y.assign(&tmp);
+
+            return;
+        };
+
+        let (mut a_tmp, mut x_tmp) = (None, None);
+        let (trans, a, lda) = raw_matrix(a, &mut a_tmp);
+        let (x, incx) = raw_vector(x, &mut x_tmp);
+
+        // The dimensions are given for the matrix as stored.
+        let (m, n) = if trans == Transpose::NoTrans { (m, n) } else { (n, m) };
+
+        unsafe { self.gemv_raw(trans, m, n, alpha, a, lda, x, incx, beta, y, incy) };
+    }
+
+    /// Computes the LU decomposition with partial pivoting of the matrix in place,
+    /// and stores the row interchanges in `ipiv`.
+    ///
+    /// The matrix is copied to a temporary array if the layout is not supported.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Singular` if a pivot is zero, where the decomposition is still
+    /// completed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `ipiv` is not the minimum of the matrix dimensions.
+    fn getrf<L: Layout>(&self, a: &mut DSlice<T, 2, L>, ipiv: &mut [usize]) -> Result<(), Error> {
+        let (m, n) = (a.dim(0), a.dim(1));
+
+        assert!(ipiv.len() == m.min(n), "length mismatch");
+
+        if let Some((Transpose::NoTrans, lda)) = blas_layout(a) {
+            unsafe { self.getrf_raw(m, n, a.as_mut_ptr(), lda, ipiv) }
+        } else {
+            let mut tmp = a.to_tensor();
+            let result = self.getrf(&mut tmp, ipiv);
+
+            a.assign(&tmp);
+
+            result
+        }
+    }
+
+    /// Computes the Cholesky decomposition of the matrix in place, where only the
+    /// lower triangle is accessed.
+    ///
+    /// The matrix is copied to a temporary array if the layout is not supported.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NotPositiveDefinite` if the matrix is not positive definite.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
+    fn potrf<L: Layout>(&self, a: &mut DSlice<T, 2, L>) -> Result<(), Error> {
+        let n = a.dim(0);
+
+        assert!(a.dim(1) == n, "matrix not square");
+
+        if let Some((Transpose::NoTrans, lda)) = blas_layout(a) {
+            unsafe { self.potrf_raw(n, a.as_mut_ptr(), lda) }
+        } else {
+            let mut tmp = a.to_tensor();
+            let result = self.potrf(&mut tmp);
+
+            a.assign(&tmp);
+
+            result
+        }
+    }
+}
+
+/// Pure-Rust reference implementation of the backend trait.
+///
+/// # Examples
+///
+/// ```
+/// use mdarray::backend::{Backend, Reference};
+/// use mdarray::{tensor, view};
+///
+/// let a = tensor![[1.0, 2.0], [3.0, 4.0]];
+/// let b = tensor![[1.0, 0.0], [1.0, 1.0]];
+/// let mut c = tensor![[0.0, 0.0], [0.0, 0.0]];
+///
+/// Reference.gemm(1.0, &a.transpose(), &b, 0.0, &mut c);
+///
+/// assert_eq!(c, view![[4.0, 3.0], [6.0, 4.0]]);
+/// ```
+#[cfg(feature = "num-traits")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Reference;
+
+#[cfg(feature = "num-traits")]
+impl<T: Default + Float> Backend<T> for Reference {
+    unsafe fn axpy_raw(
+        &self,
+        n: usize,
+        alpha: T,
+        x: *const T,
+        incx: isize,
+        y: *mut T,
+        incy: isize,
+    ) {
+        let x = unsafe { vector(x, n, incx) };
+        let mut y = unsafe { vector_mut(y, n, incy) };
+
+        y.expr_mut().zip(&x).for_each(|(y, &x)| *y = alpha * x + *y);
+    }
+
+    unsafe fn dot_raw(&self, n: usize, x: *const T, incx: isize, y: *const T, incy: isize) -> T {
+        let (x, y) = unsafe { (vector(x, n, incx), vector(y, n, incy)) };
+
+        x.iter().zip(&y).fold(T::zero(), |acc, (&x, &y)| acc + x * y)
+    }
+
+    unsafe fn gemm_raw(
+        &self,
+        trans_a: Transpose,
+        trans_b: Transpose,
+        m: usize,
+        n: usize,
+        k: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        b: *const T,
+        ldb: usize,
+        beta: T,
+        c: *mut T,
+        ldc: usize,
+    ) {
+        let a = unsafe { matrix(a, [m, k], lda, trans_a) };
+        let b = unsafe { matrix(b, [k, n], ldb, trans_b) };
+        let mut c = unsafe { matrix_mut(c, [m, n], ldc) };
+
+        let mut ab = Tensor::zeros([m, n]);
+
+        matmul::gemm(&a, &b, &mut ab);
+
+        c.expr_mut().zip(&ab).for_each(|(c, &ab)| scale_add(alpha, ab, beta, c));
+    }
+
+    unsafe fn gemv_raw(
+        &self,
+        trans: Transpose,
+        m: usize,
+        n: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        x: *const T,
+        incx: isize,
+        beta: T,
+        y: *mut T,
+        incy: isize,
+    ) {
+        let shape = if trans == Transpose::NoTrans { [m, n] } else { [n, m] };
+
+        let a = unsafe { matrix(a, shape, lda, trans) };
+        let x = unsafe { vector(x, shape[1], incx) };
+        let mut y = unsafe { vector_mut(y, shape[0], incy) };
+
+        y.expr_mut().zip(a.rows()).for_each(|(y, row)| {
+            let ax = row.iter().zip(&x).fold(T::zero(), |acc, (&a, &x)| acc + a * x);
+
+            scale_add(alpha, ax, beta, y);
+        });
+    }
+
+    unsafe fn getrf_raw(
+        &self,
+        m: usize,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        ipiv: &mut [usize],
+    ) -> Result<(), Error> {
+        let mut a = unsafe { matrix_mut(a, [m, n], lda) };
+
+        linalg::lu_in_place(&mut *a, ipiv)
+    }
+
+    unsafe fn potrf_raw(&self, n: usize, a: *mut T, lda: usize) -> Result<(), Error> {
+        let mut a = unsafe { matrix_mut(a, [n, n], lda) };
+
+        linalg::cholesky_in_place(&mut *a)
+    }
+}
+
+// Returns the transposition and leading dimension for a matrix in row-major order,
+// or `None` if the strides are not supported.
+fn blas_layout<T, L: Layout>(a: &DSlice<T, 2, L>) -> Option<(Transpose, usize)> {
+    let (rows, cols) = (a.dim(0), a.dim(1));
+    let (row_stride, col_stride) = (a.stride(0), a.stride(1));
+
+    // Strides for dimensions of length one are not used, and can have any value.
+    if (cols < 2 || col_stride == 1) && (rows < 2 || row_stride >= cols.max(1) as isize) {
+        Some((Transpose::NoTrans, if rows < 2 { cols.max(1) } else { row_stride as usize }))
+    } else if (rows < 2 || row_stride == 1) && (cols < 2 || col_stride >= rows.max(1) as isize) {
+        Some((Transpose::Trans, if cols < 2 { rows.max(1) } else { col_stride as usize }))
+    } else {
+        None
+    }
+}
+
+// Returns the offset from the first element to the element with the lowest address
+// for a vector with the given length and increment.
+fn lowest_offset(n: usize, inc: isize) -> isize {
+    if inc < 0 && n > 0 { (n - 1) as isize * inc } else { 0 }
+}
+
+fn flip(trans: Transpose) -> Transpose {
+    match trans {
+        Transpose::NoTrans => Transpose::Trans,
+        Transpose::Trans => Transpose::NoTrans,
+    }
+}
+
+// Returns the transposition, pointer and leading dimension for a matrix, where the
+// matrix is copied to the temporary array if the layout is not supported.
+fn raw_matrix<T: Copy, L: Layout>(
+    a: &DSlice<T, 2, L>,
+    tmp: &mut Option<DTensor<T, 2>>,
+) -> (Transpose, *const T, usize) {
+    match blas_layout(a) {
+        Some((trans, lda)) => (trans, a.as_ptr(), lda),
+        None => (Transpose::NoTrans, tmp.insert(a.to_tensor()).as_ptr(), a.dim(1).max(1)),
+    }
+}
+
+// Returns the pointer and increment for a vector as in BLAS, where the vector is
+// copied to the temporary array if the stride is zero.
+fn raw_vector<T: Copy, L: Layout>(
+    x: &DSlice<T, 1, L>,
+    tmp: &mut Option<DTensor<T, 1>>,
+) -> (*const T, isize) {
+    match (x.dim(0), x.stride(0)) {
+        // The stride for a vector of length zero or one is not used, and can have any value.
+        (0 | 1, _) => (x.as_ptr(), 1),
+        (_, 0) => (tmp.insert(x.to_tensor()).as_ptr(), 1),
+        (n, inc) => (unsafe { x.as_ptr().offset(lowest_offset(n, inc)) }, inc),
+    }
+}
+
+// Returns the pointer and increment for a mutable vector as in BLAS, or `None` if
+// the stride is zero.
+fn raw_vector_mut<T, L: Layout>(y: &mut DSlice<T, 1, L>) -> Option<(*mut T, isize)> {
+    match (y.dim(0), y.stride(0)) {
+        (0 | 1, _) => Some((y.as_mut_ptr(), 1)),
+        (_, 0) => None,
+        (n, inc) => Some((unsafe { y.as_mut_ptr().offset(lowest_offset(n, inc)) }, inc)),
+    }
+}
+
+#[cfg(feature = "num-traits")]
+unsafe fn matrix<'a, T>(
+    ptr: *const T,
+    shape: [usize; 2],
+    ld: usize,
+    trans: Transpose,
+) -> DView<'a, T, 2, Strided> {
+    let strides = match trans {
+        Transpose::NoTrans => [ld as isize, 1],
+        Transpose::Trans => [1, ld as isize],
+    };
+
+    unsafe { View::new_unchecked(ptr, StridedMapping::new(shape.into_shape(), &strides)) }
+}
+
+#[cfg(feature = "num-traits")]
+unsafe fn matrix_mut<'a, T>(
+    ptr: *mut T,
+    shape: [usize; 2],
+    ld: usize,
+) -> DViewMut<'a, T, 2, Strided> {
+    unsafe {
+        ViewMut::new_unchecked(ptr, StridedMapping::new(shape.into_shape(), &[ld as isize, 1]))
+    }
+}
+
+// Computes `y = alpha * x + beta * y`, where `y` is not read if `beta` is zero.
+#[cfg(feature = "num-traits")]
+fn scale_add<T: Float>(alpha: T, x: T, beta: T, y: &mut T) {
+    *y = if beta == T::zero() { alpha * x } else { alpha * x + beta * *y };
+}
+
+// Returns a view of a vector given as in BLAS, where the pointer is to the element
+// with the lowest address if the increment is negative.
+#[cfg(feature = "num-traits")]
+unsafe fn vector<'a, T>(ptr: *const T, n: usize, inc: isize) -> DView<'a, T, 1, Strided> {
+    let mapping = StridedMapping::new([n].into_shape(), &[inc]);
+
+    unsafe { View::new_unchecked(ptr.offset(-lowest_offset(n, inc)), mapping) }
+}
+
+#[cfg(feature = "num-traits")]
+unsafe fn vector_mut<'a, T>(ptr: *mut T, n: usize, inc: isize) -> DViewMut<'a, T, 1, Strided> {
+    let mapping = StridedMapping::new([n].into_shape(), &[inc]);
+
+    unsafe { ViewMut::new_unchecked(ptr.offset(-lowest_offset(n, inc)), mapping) }
+}
@@ -0,0 +1,115 @@
+use core::ops::Neg;
+
+use num_complex::Complex;
+
+use crate::expr::{Expression, Map};
+use crate::layout::{Layout, Strided};
+use crate::mapping::StridedMapping;
+use crate::shape::Shape;
+use crate::slice::Slice;
+use crate::view::{View, ViewMut};
+
+impl<T, S: Shape, L: Layout> Slice<Complex<T>, S, L> {
+    /// Returns an expression with the complex conjugate of each element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::expr::Expression;
+    /// use mdarray::{tensor, view};
+    /// use num_complex::Complex;
+    ///
+    /// let t = tensor![Complex::new(1.0, 2.0), Complex::new(3.0, -4.0)];
+    ///
+    /// assert_eq!(t.conj().eval(), view![Complex::new(1.0, -2.0), Complex::new(3.0, 4.0)]);
+    /// ```
+    pub fn conj(&self) -> Map<View<'_, Complex<T>, S, L>, fn(&Complex<T>) -> Complex<T>>
+    where
+        T: Clone + Neg<Output = T>,
+    {
+        self.expr().map(conj as _)
+    }
+
+    /// Returns an expression with the conjugate transpose of the array, where the
+    /// dimensions are reversed and each element is conjugated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::expr::Expression;
+    /// use mdarray::{tensor, view};
+    /// use num_complex::Complex;
+    ///
+    /// let t = tensor![[Complex::new(1.0, 1.0), Complex::new(2.0, 0.0)]];
+    ///
+    /// assert_eq!(t.conj_transpose().eval(), view![[Complex::new(1.0, -1.0)], [Complex::new(2.0, 0.0)]]);
+    /// ```
+    pub fn conj_transpose(
+        &self,
+    ) -> Map<
+        View<'_, Complex<T>, S::Reverse, <S::Tail as Shape>::Layout<L>>,
+        fn(&Complex<T>) -> Complex<T>,
+    >
+    where
+        T: Clone + Neg<Output = T>,
+    {
+        self.transpose().map(conj as _)
+    }
+
+    /// Returns an array view of the imaginary parts of the elements.
+    pub fn im(&self) -> View<'_, T, S, Strided> {
+        let offset = if self.is_empty() { 0 } else { 1 };
+
+        unsafe { View::new_unchecked((self.as_ptr() as *const T).add(offset), self.parts()) }
+    }
+
+    /// Returns a mutable array view of the imaginary parts of the elements.
+    pub fn im_mut(&mut self) -> ViewMut<'_, T, S, Strided> {
+        let offset = if self.is_empty() { 0 } else { 1 };
+        let mapping = self.parts();
+
+        unsafe { ViewMut::new_unchecked((self.as_mut_ptr() as *mut T).add(offset), mapping) }
+    }
+
+    /// Returns an array view of the real parts of the elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::{tensor, view};
+    /// use num_complex::Complex;
+    ///
+    /// let mut t = tensor![Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)];
+    ///
+    /// t.im_mut()[1] = 5.0;
+    ///
+    /// assert_eq!(t.re(), view![1.0, 3.0]);
+    /// assert_eq!(t.im(), view![2.0, 5.0]);
+    /// ```
+    pub fn re(&self) -> View<'_, T, S, Strided> {
+        unsafe { View::new_unchecked(self.as_ptr() as *const T, self.parts()) }
+    }
+
+    /// Returns a mutable array view of the real parts of the elements.
+    pub fn re_mut(&mut self) -> ViewMut<'_, T, S, Strided> {
+        let mapping = self.parts();
+
+        unsafe { ViewMut::new_unchecked(self.as_mut_ptr() as *mut T, mapping) }
+    }
+
+    // Returns the mapping for the real or imaginary parts, which are interleaved
+    // in memory so that the strides are doubled.
+    fn parts(&self) -> StridedMapping<S> {
+        let mut strides = self.shape().new_dims::<isize>();
+
+        for i in 0..self.rank() {
+            strides.as_mut()[i] = 2 * self.stride(i);
+        }
+
+        StridedMapping::new(self.shape().clone(), strides.as_ref())
+    }
+}
+
+fn conj<T: Clone + Neg<Output = T>>(x: &Complex<T>) -> Complex<T> {
+    Complex::new(x.re.clone(), -x.im.clone())
+}
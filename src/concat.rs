@@ -0,0 +1,149 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+
+use crate::dim::Const;
+use crate::expr::{self, Expression, FromExpression, IntoExpression};
+use crate::index::{Axis, Resize};
+use crate::shape::Shape;
+use crate::tensor::Tensor;
+
+/// Concatenates arrays along the specified dimension, cloning the elements.
+///
+/// The arrays must have the same shape except in the dimension to be concatenated.
+///
+/// # Examples
+///
+/// ```
+/// use mdarray::index::Rows;
+/// use mdarray::{concat, tensor, view};
+///
+/// let a = tensor![[1, 2], [3, 4]];
+/// let b = tensor![[5], [6]];
+///
+/// assert_eq!(concat(Rows, &[a.view(.., ..), b.view(.., ..)]), view![[1, 2, 5], [3, 4, 6]]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if there are no arrays, if the dimension is out of bounds, or if the shapes
+/// are not matching except in the dimension to be concatenated.
+pub fn concat<'a, T: 'a + Clone, A: Axis, I>(
+    axis: A,
+    inputs: &[I],
+) -> Tensor<T, Resize<A, I::Shape>>
+where
+    I: Clone + IntoExpression<Item = &'a T>,
+{
+    assert!(!inputs.is_empty(), "no arrays to concatenate");
+
+    let first = inputs[0].clone().into_expr();
+    let index = axis.index(first.rank());
+
+    let mut sizes = Vec::with_capacity(inputs.len());
+    let mut len = 0;
+
+    for input in inputs {
+        let expr = input.clone().into_expr();
+
+        assert!(expr.rank() == first.rank(), "invalid rank");
+
+        for i in 0..first.rank() {
+            assert!(i == index || expr.dim(i) == first.dim(i), "shape mismatch");
+        }
+
+        sizes.push(expr.shape().with_dims(|dims| dims[index..].iter().product()));
+        len += expr.dim(index);
+    }
+
+    let shape: Resize<A, I::Shape> = first.shape().resize_dim(index, len);
+
+    FromExpression::from_expr(interleave(shape, inputs, sizes))
+}
+
+/// Stacks arrays along a new dimension, cloning the elements.
+///
+/// The arrays must have the same shape. The new dimension is inserted at the specified
+/// axis in the resulting shape, and has constant size given by the number of arrays.
+/// The result is an `Array` if all dimensions are constant-sized, and otherwise a `Tensor`.
+///
+/// # Examples
+///
+/// ```
+/// use mdarray::index::Cols;
+/// use mdarray::{stack, tensor, view};
+///
+/// let a = tensor![1, 2, 3];
+/// let b = tensor![4, 5, 6];
+///
+/// assert_eq!(stack(Cols, &[&a, &b]), view![[1, 2, 3], [4, 5, 6]]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if there are no arrays, if the dimension is out of bounds, or if the shapes
+/// are not matching.
+pub fn stack<'a, T: 'a + Clone, A: Axis, I, const N: usize>(
+    axis: A,
+    inputs: &[I; N],
+) -> <A::Insert<Const<N>, I::Shape> as Shape>::Owned<T>
+where
+    I: Clone + IntoExpression<Item = &'a T>,
+{
+    assert!(N > 0, "no arrays to stack");
+
+    let first = inputs[0].clone().into_expr();
+    let index = axis.index(first.rank() + 1);
+
+    for input in inputs {
+        let expr = input.clone().into_expr();
+
+        assert!(expr.rank() == first.rank(), "invalid rank");
+
+        for i in 0..first.rank() {
+            assert!(expr.dim(i) == first.dim(i), "shape mismatch");
+        }
+    }
+
+    let mut shape = <A::Insert<Const<N>, I::Shape>>::new(first.rank() + 1);
+
+    first.shape().with_dims(|src| {
+        shape.with_mut_dims(|dims| {
+            dims[..index].copy_from_slice(&src[..index]);
+            dims[index] = N;
+            dims[index + 1..].copy_from_slice(&src[index..]);
+        });
+    });
+
+    let size = first.shape().with_dims(|dims| dims[index..].iter().product());
+
+    interleave(shape, inputs, vec![size; N]).eval()
+}
+
+// Returns an expression that takes the given number of elements from each input in turn,
+// which gives the elements of the resulting array in order.
+pub(crate) fn interleave<'a, T: 'a + Clone, S: Shape, I>(
+    shape: S,
+    inputs: &[I],
+    sizes: Vec<usize>,
+) -> impl Expression<Item = T, Shape = S>
+where
+    I: Clone + IntoExpression<Item = &'a T>,
+{
+    let mut iters = inputs.iter().map(|x| x.clone().into_iter()).collect::<Vec<_>>();
+
+    let mut index = 0;
+    let mut next = 0;
+    let mut count = 0;
+
+    expr::from_elem(shape, ()).map(move |()| {
+        while count == 0 {
+            index = next;
+            next = (next + 1) % sizes.len();
+            count = sizes[index];
+        }
+
+        count -= 1;
+
+        iters[index].next().expect("length mismatch").clone()
+    })
+}
@@ -0,0 +1,297 @@
+//! Exchanging arrays with other libraries using the DLPack protocol.
+//!
+//! DLPack is a common in-memory tensor structure that is supported by for example
+//! NumPy and PyTorch. Arrays are exchanged without copying through a pointer to a
+//! `DLManagedTensor`, which holds a deleter that is called by the consumer when the
+//! array is no longer used. Only arrays in CPU memory are supported.
+//!
+//! # Examples
+//!
+//! ```
+//! use mdarray::dlpack::{self, ManagedTensor};
+//! use mdarray::{tensor, view};
+//!
+//! let ptr = dlpack::into_dlpack(tensor![[1.0, 2.0], [3.0, 4.0]]);
+//! let mut t = unsafe { ManagedTensor::<f64, (usize, usize)>::from_raw(ptr).unwrap() };
+//!
+//! t[[0, 1]] = 5.0;
+//!
+//! assert_eq!(t.transpose(), view![[1.0, 3.0], [5.0, 4.0]]);
+//! ```
+
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use core::ffi::c_void;
+use core::fmt::{self, Debug, Formatter};
+use core::mem::ManuallyDrop;
+use core::ops::{Deref, DerefMut};
+use core::ptr::NonNull;
+
+#[cfg(feature = "num-complex")]
+use num_complex::Complex;
+
+use crate::error::Error;
+use crate::layout::Strided;
+use crate::raw_slice::RawSlice;
+use crate::shape::{DynRank, Shape};
+use crate::slice::Slice;
+use crate::tensor::Tensor;
+use crate::view::ViewMut;
+
+/// Device where the array memory is located.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[repr(C)]
+pub struct DLDevice {
+    /// Device type, where only `DLDevice::CPU` is supported.
+    pub device_type: i32,
+    /// Device index, which is zero for CPU memory.
+    pub device_id: i32,
+}
+
+/// Element data type description.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[repr(C)]
+pub struct DLDataType {
+    /// Type code, for example `DLDataType::FLOAT`.
+    pub code: u8,
+    /// Number of bits in each lane.
+    pub bits: u8,
+    /// Number of lanes, which is one for scalar types.
+    pub lanes: u16,
+}
+
+/// Array descriptor without memory management.
+#[derive(Debug)]
+#[repr(C)]
+pub struct DLTensor {
+    /// Pointer to the array memory, which is offset by `byte_offset`.
+    pub data: *mut c_void,
+    /// Device where the array memory is located.
+    pub device: DLDevice,
+    /// Array rank.
+    pub ndim: i32,
+    /// Element data type.
+    pub dtype: DLDataType,
+    /// Pointer to the array dimensions.
+    pub shape: *mut i64,
+    /// Pointer to the array strides in number of elements, or null if the array
+    /// is contiguous in row-major order.
+    pub strides: *mut i64,
+    /// Offset in bytes to the first element.
+    pub byte_offset: u64,
+}
+
+/// Array descriptor with a deleter, for passing ownership between libraries.
+#[derive(Debug)]
+#[repr(C)]
+pub struct DLManagedTensor {
+    /// Array descriptor.
+    pub dl_tensor: DLTensor,
+    /// Context for the producer of the array.
+    pub manager_ctx: *mut c_void,
+    /// Function to be called by the consumer to release the array.
+    pub deleter: Option<unsafe extern "C" fn(*mut DLManagedTensor)>,
+}
+
+/// Element type that can be exchanged with DLPack.
+pub trait Element {
+    /// Data type description for the element type.
+    const DTYPE: DLDataType;
+}
+
+/// Array imported from DLPack, which calls the deleter when dropped.
+pub struct ManagedTensor<T, S: Shape = DynRank> {
+    slice: RawSlice<T, S, Strided>,
+    managed: NonNull<DLManagedTensor>,
+}
+
+// Context for an exported array, which holds the array and the dimensions and strides.
+struct Context<T, S: Shape> {
+    _tensor: Tensor<T, S>,
+    _shape: Vec<i64>,
+    _strides: Vec<i64>,
+}
+
+impl DLDevice {
+    /// Device type for CPU memory.
+    pub const CPU: i32 = 1;
+}
+
+impl DLDataType {
+    /// Type code for signed integers.
+    pub const INT: u8 = 0;
+
+    /// Type code for unsigned integers.
+    pub const UINT: u8 = 1;
+
+    /// Type code for floating point numbers.
+    pub const FLOAT: u8 = 2;
+
+    /// Type code for complex numbers.
+    pub const COMPLEX: u8 = 5;
+
+    /// Type code for booleans.
+    pub const BOOL: u8 = 6;
+
+    const fn new(code: u8, bits: u8) -> Self {
+        Self { code, bits, lanes: 1 }
+    }
+}
+
+macro_rules! impl_element {
+    ($type:ty, $code:ident, $bits:literal) => {
+        impl Element for $type {
+            const DTYPE: DLDataType = DLDataType::new(DLDataType::$code, $bits);
+        }
+    };
+}
+
+impl_element!(bool, BOOL, 8);
+impl_element!(i8, INT, 8);
+impl_element!(i16, INT, 16);
+impl_element!(i32, INT, 32);
+impl_element!(i64, INT, 64);
+impl_element!(u8, UINT, 8);
+impl_element!(u16, UINT, 16);
+impl_element!(u32, UINT, 32);
+impl_element!(u64, UINT, 64);
+impl_element!(f32, FLOAT, 32);
+impl_element!(f64, FLOAT, 64);
+
+#[cfg(feature = "num-complex")]
+impl_element!(Complex<f32>, COMPLEX, 64);
+#[cfg(feature = "num-complex")]
+impl_element!(Complex<f64>, COMPLEX, 128);
+
+impl<T: Element, S: Shape> ManagedTensor<T, S> {
+    /// Creates an array from a pointer to a managed tensor, and takes ownership.
+    ///
+    /// The shape type can be `DynRank` to accept any rank, or otherwise the rank and
+    /// any constant-sized dimensions are checked to match the managed tensor.
+    ///
+    /// # Errors
+    ///
+    /// If the device or data type is not supported, if the shape is not matching, or
+    /// if the pointer or strides are not valid, then an error is returned and the
+    /// ownership is not taken.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be a valid managed tensor, and the array memory must not be
+    /// accessed by others while the array is used.
+    pub unsafe fn from_raw(ptr: *mut DLManagedTensor) -> Result<Self, Error> {
+        let managed = NonNull::new(ptr).ok_or(Error::InvalidPointer)?;
+        let tensor = unsafe { &managed.as_ref().dl_tensor };
+
+        if tensor.device.device_type != DLDevice::CPU || tensor.dtype != T::DTYPE {
+            return Err(Error::Unsupported);
+        }
+
+        let rank = usize::try_from(tensor.ndim).map_err(|_| Error::RankMismatch)?;
+        let mut dims = vec![0; rank];
+        let mut strides = vec![0; rank];
+
+        for i in 0..rank {
+            let size = unsafe { *tensor.shape.add(i) };
+
+            dims[i] = usize::try_from(size).map_err(|_| Error::ShapeMismatch)?;
+        }
+
+        if tensor.strides.is_null() {
+            let mut stride = 1isize;
+
+            for i in (0..rank).rev() {
+                strides[i] = stride;
+                stride = stride.saturating_mul(dims[i] as isize);
+            }
+        } else {
+            for i in 0..rank {
+                let stride = unsafe { *tensor.strides.add(i) };
+
+                strides[i] = isize::try_from(stride).map_err(|_| Error::Overflow)?;
+            }
+        }
+
+        let shape = S::try_from_dims(&dims)?;
+        let offset = usize::try_from(tensor.byte_offset).map_err(|_| Error::Overflow)?;
+
+        // Empty arrays may have a null pointer, which is replaced with a dangling pointer.
+        let data = if tensor.data.is_null() && shape.checked_len() == Some(0) {
+            NonNull::<T>::dangling().as_ptr()
+        } else {
+            tensor.data.wrapping_byte_add(offset) as *mut T
+        };
+
+        let view = unsafe { ViewMut::try_from_raw_parts(data, shape, &strides)? };
+        let (ptr, mapping) = view.into_raw_parts();
+
+        Ok(Self { slice: unsafe { RawSlice::new_unchecked(ptr, mapping) }, managed })
+    }
+
+    /// Returns the pointer to the managed tensor, and releases ownership.
+    pub fn into_raw(self) -> *mut DLManagedTensor {
+        ManuallyDrop::new(self).managed.as_ptr()
+    }
+}
+
+impl<T: Debug, S: Shape> Debug for ManagedTensor<T, S> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+impl<T, S: Shape> Deref for ManagedTensor<T, S> {
+    type Target = Slice<T, S, Strided>;
+
+    fn deref(&self) -> &Self::Target {
+        self.slice.as_slice()
+    }
+}
+
+impl<T, S: Shape> DerefMut for ManagedTensor<T, S> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.slice.as_mut_slice()
+    }
+}
+
+impl<T, S: Shape> Drop for ManagedTensor<T, S> {
+    fn drop(&mut self) {
+        if let Some(deleter) = unsafe { self.managed.as_ref().deleter } {
+            unsafe { deleter(self.managed.as_ptr()) };
+        }
+    }
+}
+
+/// Converts an array into a pointer to a managed tensor, and releases ownership.
+///
+/// The array is dropped when the deleter of the managed tensor is called.
+pub fn into_dlpack<T: Element, S: Shape>(mut tensor: Tensor<T, S>) -> *mut DLManagedTensor {
+    let mut shape = (0..tensor.rank()).map(|i| tensor.dim(i) as i64).collect::<Vec<_>>();
+    let mut strides = (0..tensor.rank()).map(|i| tensor.stride(i) as i64).collect::<Vec<_>>();
+
+    let dl_tensor = DLTensor {
+        data: tensor.as_mut_ptr() as *mut c_void,
+        device: DLDevice { device_type: DLDevice::CPU, device_id: 0 },
+        ndim: tensor.rank() as i32,
+        dtype: T::DTYPE,
+        shape: shape.as_mut_ptr(),
+        strides: strides.as_mut_ptr(),
+        byte_offset: 0,
+    };
+
+    let context = Context { _tensor: tensor, _shape: shape, _strides: strides };
+
+    let managed = DLManagedTensor {
+        dl_tensor,
+        manager_ctx: Box::into_raw(Box::new(context)) as *mut c_void,
+        deleter: Some(deleter::<T, S>),
+    };
+
+    Box::into_raw(Box::new(managed))
+}
+
+unsafe extern "C" fn deleter<T, S: Shape>(ptr: *mut DLManagedTensor) {
+    let managed = unsafe { Box::from_raw(ptr) };
+
+    drop(unsafe { Box::from_raw(managed.manager_ctx as *mut Context<T, S>) });
+}
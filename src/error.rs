@@ -0,0 +1,53 @@
+use core::error;
+use core::fmt::{self, Display, Formatter};
+
+/// Error type for fallible array operations.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// A dimension or an index is out of bounds.
+    IndexOutOfBounds,
+    /// The dimensions do not form a permutation.
+    InvalidPermutation,
+    /// The pointer is null or not properly aligned.
+    InvalidPointer,
+    /// The strides are not a multiple of the element size, or elements are overlapping
+    /// in a mutable array view.
+    InvalidStrides,
+    /// The memory layout is not compatible with the requested layout.
+    NotContiguous,
+    /// The matrix is not positive definite.
+    NotPositiveDefinite,
+    /// The number of elements or the size in bytes exceeds `isize::MAX`.
+    Overflow,
+    /// The array rank is not matching.
+    RankMismatch,
+    /// The array shape is not matching.
+    ShapeMismatch,
+    /// The matrix is singular.
+    Singular,
+    /// The device or data type is not supported.
+    Unsupported,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            Error::IndexOutOfBounds => "index out of bounds",
+            Error::InvalidPermutation => "invalid permutation",
+            Error::InvalidPointer => "invalid pointer",
+            Error::InvalidStrides => "invalid strides",
+            Error::NotContiguous => "memory layout not compatible",
+            Error::NotPositiveDefinite => "matrix not positive definite",
+            Error::Overflow => "length overflow",
+            Error::RankMismatch => "rank mismatch",
+            Error::ShapeMismatch => "shape mismatch",
+            Error::Singular => "singular matrix",
+            Error::Unsupported => "unsupported device or data type",
+        };
+
+        f.write_str(msg)
+    }
+}
+
+impl error::Error for Error {}
@@ -1,7 +1,10 @@
 use core::fmt::{Debug, Formatter, Result};
 
+use crate::dim::Dim;
 use crate::expr::expression::{Expression, IntoExpression};
 use crate::expr::iter::Iter;
+#[cfg(feature = "rayon")]
+use crate::expr::par::ParExpression;
 use crate::shape::Shape;
 
 /// Expression that clones the elements of an underlying expression.
@@ -36,6 +39,7 @@ pub struct Zip<A: Expression, B: Expression> {
     a: A,
     b: B,
     shape: <Self as Expression>::Shape,
+    inner_limit: usize,
 }
 
 /// Creates an expression that clones the elements of the argument.
@@ -163,6 +167,25 @@ impl<'a, T: 'a + Clone, E: Expression<Item = &'a T>> IntoIterator for Cloned<E>
     }
 }
 
+#[cfg(feature = "rayon")]
+impl<'a, T: 'a + Clone, E: ParExpression<Item = &'a T>> ParExpression for Cloned<E> {
+    type Part = Cloned<E::Part>;
+
+    fn clone_part(&self) -> Self::Part {
+        Cloned::new(self.expr.clone_part())
+    }
+
+    fn into_part(self) -> Self::Part {
+        Cloned::new(self.expr.into_part())
+    }
+
+    fn split_part(self, mid: usize) -> (Self::Part, Self::Part) {
+        let (first, second) = self.expr.split_part(mid);
+
+        (Cloned::new(first), Cloned::new(second))
+    }
+}
+
 impl<E> Copied<E> {
     pub(crate) fn new(expr: E) -> Self {
         Self { expr }
@@ -208,6 +231,25 @@ impl<'a, T: 'a + Copy, E: Expression<Item = &'a T>> IntoIterator for Copied<E> {
     }
 }
 
+#[cfg(feature = "rayon")]
+impl<'a, T: 'a + Copy, E: ParExpression<Item = &'a T>> ParExpression for Copied<E> {
+    type Part = Copied<E::Part>;
+
+    fn clone_part(&self) -> Self::Part {
+        Copied::new(self.expr.clone_part())
+    }
+
+    fn into_part(self) -> Self::Part {
+        Copied::new(self.expr.into_part())
+    }
+
+    fn split_part(self, mid: usize) -> (Self::Part, Self::Part) {
+        let (first, second) = self.expr.split_part(mid);
+
+        (Copied::new(first), Copied::new(second))
+    }
+}
+
 impl<E: Expression> Enumerate<E> {
     pub(crate) fn new(expr: E) -> Self {
         Self { expr, count: 0 }
@@ -261,6 +303,26 @@ impl<E: Expression> IntoIterator for Enumerate<E> {
     }
 }
 
+#[cfg(feature = "rayon")]
+impl<E: ParExpression> ParExpression for Enumerate<E> {
+    type Part = Enumerate<E::Part>;
+
+    fn clone_part(&self) -> Self::Part {
+        Enumerate { expr: self.expr.clone_part(), count: self.count }
+    }
+
+    fn into_part(self) -> Self::Part {
+        Enumerate { expr: self.expr.into_part(), count: self.count }
+    }
+
+    fn split_part(self, mid: usize) -> (Self::Part, Self::Part) {
+        let count = self.count + mid * (self.expr.len() / self.expr.dim(0));
+        let (first, second) = self.expr.split_part(mid);
+
+        (Enumerate { expr: first, count: self.count }, Enumerate { expr: second, count })
+    }
+}
+
 impl<E, F> Map<E, F> {
     pub(crate) fn new(expr: E, f: F) -> Self {
         Self { expr, f }
@@ -312,24 +374,73 @@ impl<T, E: Expression, F: FnMut(E::Item) -> T> IntoIterator for Map<E, F> {
     }
 }
 
+#[cfg(feature = "rayon")]
+impl<T, E: ParExpression, F: Clone + FnMut(E::Item) -> T + Send> ParExpression for Map<E, F> {
+    type Part = Map<E::Part, F>;
+
+    fn clone_part(&self) -> Self::Part {
+        Map::new(self.expr.clone_part(), self.f.clone())
+    }
+
+    fn into_part(self) -> Self::Part {
+        Map::new(self.expr.into_part(), self.f)
+    }
+
+    fn split_part(self, mid: usize) -> (Self::Part, Self::Part) {
+        let (first, second) = self.expr.split_part(mid);
+
+        (Map::new(first, self.f.clone()), Map::new(second, self.f))
+    }
+}
+
 impl<A: Expression, B: Expression> Zip<A, B> {
     pub(crate) fn new(a: A, b: B) -> Self {
-        assert!(A::IS_REPEATABLE || a.rank() >= b.rank(), "expression not repeatable");
-        assert!(B::IS_REPEATABLE || b.rank() >= a.rank(), "expression not repeatable");
+        const {
+            let valid =
+                is_mergeable::<<A::Shape as Shape>::Reverse, <B::Shape as Shape>::Reverse>();
+
+            assert!(valid, "incompatible constant-sized dimensions");
+        }
+
+        let mut a_broadcast = a.rank() < b.rank();
+        let mut b_broadcast = b.rank() < a.rank();
+
+        let mut inner_limit = usize::MAX;
 
         let shape = a.shape().with_dims(|a_dims| {
             b.shape().with_dims(|b_dims| {
-                let dims = if a_dims.len() < b_dims.len() { b_dims } else { a_dims };
-                let inner_match =
-                    a_dims[dims.len() - b_dims.len()..] == b_dims[dims.len() - a_dims.len()..];
+                let rank = a_dims.len().max(b_dims.len());
+                let mut shape = <Self as Expression>::Shape::new(rank);
+
+                shape.with_mut_dims(|dims| {
+                    // Match dimensions from the end, where missing or size-1 dimensions
+                    // are stretched to the size of the other expression.
+                    for i in 1..=rank {
+                        let x = if i <= a_dims.len() { a_dims[a_dims.len() - i] } else { 1 };
+                        let y = if i <= b_dims.len() { b_dims[b_dims.len() - i] } else { 1 };
+
+                        assert!(x == y || x == 1 || y == 1, "incompatible shapes");
 
-                assert!(inner_match, "inner dimensions mismatch");
+                        if x != y {
+                            a_broadcast |= x == 1;
+                            b_broadcast |= y == 1;
 
-                Shape::from_dims(dims)
+                            // Broadcast dimensions must be stepped separately.
+                            inner_limit = inner_limit.min(i - 1);
+                        }
+
+                        dims[rank - i] = if x == 1 { y } else { x };
+                    }
+                });
+
+                shape
             })
         });
 
-        Self { a, b, shape }
+        assert!(A::IS_REPEATABLE || !a_broadcast, "expression not repeatable");
+        assert!(B::IS_REPEATABLE || !b_broadcast, "expression not repeatable");
+
+        Self { a, b, shape, inner_limit }
     }
 }
 
@@ -357,18 +468,19 @@ where
     }
 
     fn inner_rank(&self) -> usize {
-        self.a.inner_rank().min(self.b.inner_rank())
+        self.a.inner_rank().min(self.b.inner_rank()).min(self.inner_limit)
     }
 
     unsafe fn reset_dim(&mut self, index: usize, count: usize) {
         let delta = self.shape.rank() - index;
+        let size = self.shape.dim(index);
 
         unsafe {
-            if delta <= self.a.rank() {
+            if delta <= self.a.rank() && self.a.dim(self.a.rank() - delta) == size {
                 self.a.reset_dim(self.a.rank() - delta, count);
             }
 
-            if delta <= self.b.rank() {
+            if delta <= self.b.rank() && self.b.dim(self.b.rank() - delta) == size {
                 self.b.reset_dim(self.b.rank() - delta, count);
             }
         }
@@ -376,13 +488,14 @@ where
 
     unsafe fn step_dim(&mut self, index: usize) {
         let delta = self.shape.rank() - index;
+        let size = self.shape.dim(index);
 
         unsafe {
-            if delta <= self.a.rank() {
+            if delta <= self.a.rank() && self.a.dim(self.a.rank() - delta) == size {
                 self.a.step_dim(self.a.rank() - delta);
             }
 
-            if delta <= self.b.rank() {
+            if delta <= self.b.rank() && self.b.dim(self.b.rank() - delta) == size {
                 self.b.step_dim(self.b.rank() - delta);
             }
         }
@@ -397,3 +510,53 @@ impl<A: Expression, B: Expression> IntoIterator for Zip<A, B> {
         Iter::new(self)
     }
 }
+
+#[cfg(feature = "rayon")]
+impl<A: ParExpression, B: ParExpression> ParExpression for Zip<A, B> {
+    type Part = Zip<A::Part, B::Part>;
+
+    fn clone_part(&self) -> Self::Part {
+        Zip::new(self.a.clone_part(), self.b.clone_part())
+    }
+
+    fn into_part(self) -> Self::Part {
+        Zip::new(self.a.into_part(), self.b.into_part())
+    }
+
+    fn split_part(self, mid: usize) -> (Self::Part, Self::Part) {
+        let rank = self.shape.rank();
+        let size = self.shape.dim(0);
+
+        // Expressions broadcast in the first dimension are cloned to both parts.
+        let (a_first, a_second) = if self.a.rank() == rank && self.a.dim(0) == size {
+            self.a.split_part(mid)
+        } else {
+            (self.a.clone_part(), self.a.into_part())
+        };
+
+        let (b_first, b_second) = if self.b.rank() == rank && self.b.dim(0) == size {
+            self.b.split_part(mid)
+        } else {
+            (self.b.clone_part(), self.b.into_part())
+        };
+
+        (Zip::new(a_first, b_first), Zip::new(a_second, b_second))
+    }
+}
+
+// Checks that constant-sized dimensions in the same position have equal size, since
+// a constant-sized dimension of size one cannot be stretched in the merged shape.
+// Other mismatches are detected at runtime. The shapes are reversed.
+const fn is_mergeable<S: Shape, R: Shape>() -> bool {
+    match (S::RANK, R::RANK) {
+        (Some(m), Some(n)) if m > 0 && n > 0 => {
+            let valid = match (<S::Head as Dim>::SIZE, <R::Head as Dim>::SIZE) {
+                (Some(x), Some(y)) => x == y,
+                _ => true,
+            };
+
+            valid && is_mergeable::<S::Tail, R::Tail>()
+        }
+        _ => true,
+    }
+}
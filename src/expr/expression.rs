@@ -1,3 +1,7 @@
+use core::borrow::Borrow;
+use core::iter::{Product, Sum};
+use core::ops::Div;
+
 #[cfg(feature = "nightly")]
 use std::alloc::Allocator;
 
@@ -5,6 +9,7 @@ use std::alloc::Allocator;
 use crate::alloc::Allocator;
 use crate::expr::adapters::{Cloned, Copied, Enumerate, Map, Zip};
 use crate::expr::iter::Iter;
+use crate::index::{Axis, Resize};
 #[cfg(not(feature = "std"))]
 use crate::prelude::*;
 use crate::shape::Shape;
@@ -41,6 +46,28 @@ pub trait Expression: IntoIterator {
     /// Returns the array shape.
     fn shape(&self) -> &Self::Shape;
 
+    /// Returns `true` if all elements of the boolean expression are `true`.
+    ///
+    /// The evaluation stops at the first element that is `false`. If the expression is
+    /// empty, `true` is returned.
+    fn all(self) -> bool
+    where
+        Self: Expression<Item: Borrow<bool>> + Sized,
+    {
+        Iter::new(self).all(|x| *x.borrow())
+    }
+
+    /// Returns `true` if any element of the boolean expression is `true`.
+    ///
+    /// The evaluation stops at the first element that is `true`. If the expression is
+    /// empty, `false` is returned.
+    fn any(self) -> bool
+    where
+        Self: Expression<Item: Borrow<bool>> + Sized,
+    {
+        Iter::new(self).any(|x| *x.borrow())
+    }
+
     /// Creates an expression which clones all of its elements.
     fn cloned<'a, T: 'a + Clone>(self) -> Cloned<Self>
     where
@@ -57,6 +84,14 @@ pub trait Expression: IntoIterator {
         Copied::new(self)
     }
 
+    /// Returns the number of elements of the boolean expression that are `true`.
+    fn count_true(self) -> usize
+    where
+        Self: Expression<Item: Borrow<bool>> + Sized,
+    {
+        self.fold(0, |count, x| count + *x.borrow() as usize)
+    }
+
     /// Returns the number of elements in the specified dimension.
     ///
     /// # Panics
@@ -95,6 +130,22 @@ pub trait Expression: IntoIterator {
             && self.zip(other).into_iter().all(|(x, y)| eq(x, y))
     }
 
+    /// Creates an expression that compares the elements with those of another for
+    /// equality, and gives the result for each element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the expressions cannot be broadcast to a common shape.
+    fn eq_elem<I: IntoExpression>(
+        self,
+        other: I,
+    ) -> Map<Zip<Self, I::IntoExpr>, impl FnMut((Self::Item, I::Item)) -> bool>
+    where
+        Self: Expression<Item: PartialEq<I::Item>> + Sized,
+    {
+        self.zip(other).map(|(x, y)| x == y)
+    }
+
     /// Evaluates the expression into a new array.
     ///
     /// The resulting type is `Array` if the shape has constant-sized dimensions, or
@@ -110,12 +161,15 @@ pub trait Expression: IntoIterator {
     /// Evaluates the expression with broadcasting and appends to the given array
     /// along the first dimension.
     ///
-    /// If the array is empty, it is reshaped to match the shape of the expression.
+    /// The expression is broadcast to the inner dimensions of the array, where missing
+    /// dimensions are treated as size one. If the array is empty, it is reshaped to match
+    /// the shape of the expression.
     ///
     /// # Panics
     ///
-    /// Panics if the inner dimensions do not match, if the rank is not the same and
-    /// at least 1, or if the first dimension is not dynamically-sized.
+    /// Panics if the inner dimensions cannot be broadcast, if the rank of the expression
+    /// is larger than the array rank, if the rank is not at least 1, or if the first
+    /// dimension is not dynamically-sized.
     fn eval_into<S: Shape, A: Allocator>(
         self,
         tensor: &mut Tensor<Self::Item, S, A>,
@@ -143,16 +197,92 @@ pub trait Expression: IntoIterator {
         self.fold((), |(), x| f(x));
     }
 
+    /// Creates an expression that gives `true` for each element that is greater than
+    /// or equal to the element of another expression.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the expressions cannot be broadcast to a common shape.
+    fn ge<I: IntoExpression>(
+        self,
+        other: I,
+    ) -> Map<Zip<Self, I::IntoExpr>, impl FnMut((Self::Item, I::Item)) -> bool>
+    where
+        Self: Expression<Item: PartialOrd<I::Item>> + Sized,
+    {
+        self.zip(other).map(|(x, y)| x >= y)
+    }
+
+    /// Creates an expression that gives `true` for each element that is greater than
+    /// the element of another expression.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the expressions cannot be broadcast to a common shape.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::expr::{self, Expression};
+    /// use mdarray::{tensor, view};
+    ///
+    /// let t = tensor![[1, 5], [3, 7]];
+    ///
+    /// assert_eq!(t.expr().gt(view![2, 6]).eval(), view![[false, false], [true, true]]);
+    /// assert_eq!(t.expr().gt(expr::fill(&4)).count_true(), 2);
+    /// ```
+    fn gt<I: IntoExpression>(
+        self,
+        other: I,
+    ) -> Map<Zip<Self, I::IntoExpr>, impl FnMut((Self::Item, I::Item)) -> bool>
+    where
+        Self: Expression<Item: PartialOrd<I::Item>> + Sized,
+    {
+        self.zip(other).map(|(x, y)| x > y)
+    }
+
     /// Returns `true` if the array contains no elements.
     fn is_empty(&self) -> bool {
         self.shape().is_empty()
     }
 
+    /// Creates an expression that gives `true` for each element that is less than or
+    /// equal to the element of another expression.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the expressions cannot be broadcast to a common shape.
+    fn le<I: IntoExpression>(
+        self,
+        other: I,
+    ) -> Map<Zip<Self, I::IntoExpr>, impl FnMut((Self::Item, I::Item)) -> bool>
+    where
+        Self: Expression<Item: PartialOrd<I::Item>> + Sized,
+    {
+        self.zip(other).map(|(x, y)| x <= y)
+    }
+
     /// Returns the number of elements in the array.
     fn len(&self) -> usize {
         self.shape().len()
     }
 
+    /// Creates an expression that gives `true` for each element that is less than
+    /// the element of another expression.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the expressions cannot be broadcast to a common shape.
+    fn lt<I: IntoExpression>(
+        self,
+        other: I,
+    ) -> Map<Zip<Self, I::IntoExpr>, impl FnMut((Self::Item, I::Item)) -> bool>
+    where
+        Self: Expression<Item: PartialOrd<I::Item>> + Sized,
+    {
+        self.zip(other).map(|(x, y)| x < y)
+    }
+
     /// Creates an expression that calls a closure on each element.
     fn map<T, F: FnMut(Self::Item) -> T>(self, f: F) -> Map<Self, F>
     where
@@ -161,6 +291,96 @@ pub trait Expression: IntoIterator {
         Map::new(self, f)
     }
 
+    /// Evaluates the expression and returns an array with the largest elements along the
+    /// specified dimension, where the dimension is removed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension is out of bounds, or if the dimension has zero size.
+    fn max_axis<A: Axis>(self, axis: A) -> <A::Remove<Self::Shape> as Shape>::Owned<Self::Item>
+    where
+        Self: Expression<Item: Clone + PartialOrd> + Sized,
+    {
+        let tensor: Tensor<Self::Item, Self::Shape> = FromExpression::from_expr(self);
+
+        tensor.max_axis(axis)
+    }
+
+    /// Evaluates the expression and returns an array with the largest elements along the
+    /// specified dimension, where the dimension is kept with size one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension is out of bounds, or if the dimension has zero size.
+    fn max_axis_keepdims<A: Axis>(self, axis: A) -> Tensor<Self::Item, Resize<A, Self::Shape>>
+    where
+        Self: Expression<Item: Clone + PartialOrd> + Sized,
+    {
+        let tensor: Tensor<Self::Item, Self::Shape> = FromExpression::from_expr(self);
+
+        tensor.max_axis_keepdims(axis)
+    }
+
+    /// Evaluates the expression and returns an array with the mean of the elements along the
+    /// specified dimension, where the dimension is removed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension is out of bounds.
+    fn mean_axis<A: Axis>(self, axis: A) -> <A::Remove<Self::Shape> as Shape>::Owned<Self::Item>
+    where
+        Self: Expression<Item: Clone + Div<Output = Self::Item> + Product + Sum> + Sized,
+    {
+        let tensor: Tensor<Self::Item, Self::Shape> = FromExpression::from_expr(self);
+
+        tensor.mean_axis(axis)
+    }
+
+    /// Evaluates the expression and returns an array with the mean of the elements along the
+    /// specified dimension, where the dimension is kept with size one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension is out of bounds.
+    fn mean_axis_keepdims<A: Axis>(self, axis: A) -> Tensor<Self::Item, Resize<A, Self::Shape>>
+    where
+        Self: Expression<Item: Clone + Div<Output = Self::Item> + Product + Sum> + Sized,
+    {
+        let tensor: Tensor<Self::Item, Self::Shape> = FromExpression::from_expr(self);
+
+        tensor.mean_axis_keepdims(axis)
+    }
+
+    /// Evaluates the expression and returns an array with the smallest elements along the
+    /// specified dimension, where the dimension is removed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension is out of bounds, or if the dimension has zero size.
+    fn min_axis<A: Axis>(self, axis: A) -> <A::Remove<Self::Shape> as Shape>::Owned<Self::Item>
+    where
+        Self: Expression<Item: Clone + PartialOrd> + Sized,
+    {
+        let tensor: Tensor<Self::Item, Self::Shape> = FromExpression::from_expr(self);
+
+        tensor.min_axis(axis)
+    }
+
+    /// Evaluates the expression and returns an array with the smallest elements along the
+    /// specified dimension, where the dimension is kept with size one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension is out of bounds, or if the dimension has zero size.
+    fn min_axis_keepdims<A: Axis>(self, axis: A) -> Tensor<Self::Item, Resize<A, Self::Shape>>
+    where
+        Self: Expression<Item: Clone + PartialOrd> + Sized,
+    {
+        let tensor: Tensor<Self::Item, Self::Shape> = FromExpression::from_expr(self);
+
+        tensor.min_axis_keepdims(axis)
+    }
+
     /// Determines if the elements of the expression are not equal to those of another.
     fn ne<I: IntoExpression>(self, other: I) -> bool
     where
@@ -169,11 +389,87 @@ pub trait Expression: IntoIterator {
         !self.eq(other)
     }
 
+    /// Creates an expression that compares the elements with those of another for
+    /// inequality, and gives the result for each element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the expressions cannot be broadcast to a common shape.
+    fn ne_elem<I: IntoExpression>(
+        self,
+        other: I,
+    ) -> Map<Zip<Self, I::IntoExpr>, impl FnMut((Self::Item, I::Item)) -> bool>
+    where
+        Self: Expression<Item: PartialEq<I::Item>> + Sized,
+    {
+        self.zip(other).map(|(x, y)| x != y)
+    }
+
+    /// Evaluates the expression and returns an array with the product of the elements along the
+    /// specified dimension, where the dimension is removed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension is out of bounds.
+    fn prod_axis<A: Axis>(self, axis: A) -> <A::Remove<Self::Shape> as Shape>::Owned<Self::Item>
+    where
+        Self: Expression<Item: Clone + Product> + Sized,
+    {
+        let tensor: Tensor<Self::Item, Self::Shape> = FromExpression::from_expr(self);
+
+        tensor.prod_axis(axis)
+    }
+
+    /// Evaluates the expression and returns an array with the product of the elements along the
+    /// specified dimension, where the dimension is kept with size one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension is out of bounds.
+    fn prod_axis_keepdims<A: Axis>(self, axis: A) -> Tensor<Self::Item, Resize<A, Self::Shape>>
+    where
+        Self: Expression<Item: Clone + Product> + Sized,
+    {
+        let tensor: Tensor<Self::Item, Self::Shape> = FromExpression::from_expr(self);
+
+        tensor.prod_axis_keepdims(axis)
+    }
+
     /// Returns the array rank, i.e. the number of dimensions.
     fn rank(&self) -> usize {
         self.shape().rank()
     }
 
+    /// Evaluates the expression and returns an array with the sum of the elements along the
+    /// specified dimension, where the dimension is removed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension is out of bounds.
+    fn sum_axis<A: Axis>(self, axis: A) -> <A::Remove<Self::Shape> as Shape>::Owned<Self::Item>
+    where
+        Self: Expression<Item: Clone + Sum> + Sized,
+    {
+        let tensor: Tensor<Self::Item, Self::Shape> = FromExpression::from_expr(self);
+
+        tensor.sum_axis(axis)
+    }
+
+    /// Evaluates the expression and returns an array with the sum of the elements along the
+    /// specified dimension, where the dimension is kept with size one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension is out of bounds.
+    fn sum_axis_keepdims<A: Axis>(self, axis: A) -> Tensor<Self::Item, Resize<A, Self::Shape>>
+    where
+        Self: Expression<Item: Clone + Sum> + Sized,
+    {
+        let tensor: Tensor<Self::Item, Self::Shape> = FromExpression::from_expr(self);
+
+        tensor.sum_axis_keepdims(axis)
+    }
+
     /// Creates an expression that gives tuples `(x, y)` of the elements from each expression.
     ///
     /// # Panics
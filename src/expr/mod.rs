@@ -5,15 +5,26 @@ mod buffer;
 mod expression;
 mod into_expr;
 mod iter;
+#[cfg(feature = "num-traits")]
+mod num;
+#[cfg(feature = "rayon")]
+mod par;
 mod sources;
 
+use core::borrow::Borrow;
+
 pub use adapters::{Cloned, Copied, Enumerate, Map, Zip, cloned, copied, enumerate, map, zip};
 pub use buffer::{Buffer, Drain};
 pub use expression::{Apply, Expression, FromExpression, IntoExpression};
 pub use into_expr::IntoExpr;
 pub use iter::Iter;
-pub use sources::{AxisExpr, AxisExprMut, Lanes, LanesMut};
+#[cfg(feature = "num-traits")]
+pub use num::NumExpression;
+#[cfg(feature = "rayon")]
+pub use par::ParExpression;
+pub use sources::{AxisExpr, AxisExprMut, Chunks, ChunksExact, ChunksExactMut, ChunksMut};
 pub use sources::{Fill, FillWith, FromElem, FromFn, fill, fill_with, from_elem, from_fn};
+pub use sources::{Lanes, LanesMut, Windows};
 
 /// Folds all elements of the argument into an accumulator by applying an operation,
 /// and returns the result.
@@ -47,3 +58,37 @@ pub fn fold<T, I: IntoExpression, F: FnMut(T, I::Item) -> T>(expr: I, init: T, f
 pub fn for_each<I: IntoExpression, F: FnMut(I::Item)>(expr: I, f: F) {
     expr.into_expr().for_each(f);
 }
+
+/// Creates an expression that selects elements from `a` where the mask is `true`,
+/// and from `b` where the mask is `false`.
+///
+/// # Panics
+///
+/// Panics if the expressions cannot be broadcast to a common shape.
+///
+/// # Examples
+///
+/// ```
+/// use mdarray::expr::{self, Expression};
+/// use mdarray::{tensor, view};
+///
+/// let t = tensor![-1.0, 2.0, -3.0];
+/// let u = expr::where_(t.expr().lt(expr::fill(&0.0)), expr::fill(&0.0), &t).copied();
+///
+/// assert_eq!(u.eval(), view![0.0, 2.0, 0.0]);
+/// ```
+pub fn where_<M, I, J>(
+    mask: M,
+    a: I,
+    b: J,
+) -> Map<
+    Zip<Zip<M::IntoExpr, I::IntoExpr>, J::IntoExpr>,
+    impl FnMut(((M::Item, I::Item), J::Item)) -> I::Item,
+>
+where
+    M: IntoExpression<Item: Borrow<bool>>,
+    I: IntoExpression,
+    J: IntoExpression<Item = I::Item>,
+{
+    mask.into_expr().zip(a).zip(b).map(|((m, x), y)| if *m.borrow() { x } else { y })
+}
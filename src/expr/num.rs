@@ -0,0 +1,97 @@
+use num_traits::{Float, Signed};
+
+use crate::expr::adapters::Map;
+use crate::expr::expression::{Expression, IntoExpression};
+
+/// Trait for element-wise numeric functions.
+///
+/// The trait is implemented for all types that can be converted to an expression with
+/// references to numeric elements, such as array references and views. Each function
+/// returns an expression that is evaluated lazily.
+///
+/// # Examples
+///
+/// ```
+/// use mdarray::expr::{Expression, NumExpression};
+/// use mdarray::{tensor, view};
+///
+/// let t = tensor![1.0, 4.0, 9.0];
+///
+/// assert_eq!(t.sqrt().eval(), view![1.0, 2.0, 3.0]);
+/// assert_eq!((&t).powi(2).eval(), view![1.0, 16.0, 81.0]);
+/// ```
+pub trait NumExpression<'a, T: 'a>: IntoExpression<Item = &'a T> + Sized {
+    /// Returns an expression with the absolute value of each element.
+    fn abs(self) -> Map<Self::IntoExpr, impl FnMut(&'a T) -> T>
+    where
+        T: Signed,
+    {
+        self.into_expr().map(|x| x.abs())
+    }
+
+    /// Returns an expression with the cosine of each element.
+    fn cos(self) -> Map<Self::IntoExpr, impl FnMut(&'a T) -> T>
+    where
+        T: Float,
+    {
+        self.into_expr().map(|x| x.cos())
+    }
+
+    /// Returns an expression with the exponential function of each element.
+    fn exp(self) -> Map<Self::IntoExpr, impl FnMut(&'a T) -> T>
+    where
+        T: Float,
+    {
+        self.into_expr().map(|x| x.exp())
+    }
+
+    /// Returns an expression with the natural logarithm of each element.
+    fn ln(self) -> Map<Self::IntoExpr, impl FnMut(&'a T) -> T>
+    where
+        T: Float,
+    {
+        self.into_expr().map(|x| x.ln())
+    }
+
+    /// Returns an expression with each element raised to a floating point power.
+    fn powf(self, n: T) -> Map<Self::IntoExpr, impl FnMut(&'a T) -> T>
+    where
+        T: Float,
+    {
+        self.into_expr().map(move |x| x.powf(n))
+    }
+
+    /// Returns an expression with each element raised to an integer power.
+    fn powi(self, n: i32) -> Map<Self::IntoExpr, impl FnMut(&'a T) -> T>
+    where
+        T: Float,
+    {
+        self.into_expr().map(move |x| x.powi(n))
+    }
+
+    /// Returns an expression with the sine of each element.
+    fn sin(self) -> Map<Self::IntoExpr, impl FnMut(&'a T) -> T>
+    where
+        T: Float,
+    {
+        self.into_expr().map(|x| x.sin())
+    }
+
+    /// Returns an expression with the square root of each element.
+    fn sqrt(self) -> Map<Self::IntoExpr, impl FnMut(&'a T) -> T>
+    where
+        T: Float,
+    {
+        self.into_expr().map(|x| x.sqrt())
+    }
+
+    /// Returns an expression with the tangent of each element.
+    fn tan(self) -> Map<Self::IntoExpr, impl FnMut(&'a T) -> T>
+    where
+        T: Float,
+    {
+        self.into_expr().map(|x| x.tan())
+    }
+}
+
+impl<'a, T: 'a, I: IntoExpression<Item = &'a T>> NumExpression<'a, T> for I {}
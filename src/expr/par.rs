@@ -0,0 +1,179 @@
+use rayon::iter::plumbing::{Folder, UnindexedConsumer, UnindexedProducer, bridge_unindexed};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use rayon::vec::IntoIter;
+
+use crate::expr::expression::Expression;
+use crate::expr::sources::{AxisExpr, AxisExprMut, Lanes, LanesMut};
+use crate::index::Axis;
+use crate::layout::Layout;
+use crate::shape::Shape;
+use crate::tensor::Tensor;
+
+/// Expression trait for parallel evaluation.
+///
+/// The expression is split into parts along the outermost dimension, which are given
+/// to the rayon thread pool. Each part gets a clone of the closures in the expression,
+/// and broadcast operands in a zipped expression are cloned for each part.
+pub trait ParExpression: Expression + Send + Sized {
+    #[doc(hidden)]
+    type Part: ParExpression<Item = Self::Item, Part = Self::Part>;
+
+    /// Evaluates the expression in parallel into a new array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::expr::{Expression, ParExpression};
+    /// use mdarray::{tensor, view};
+    ///
+    /// let t = tensor![[1, 2], [3, 4]];
+    ///
+    /// assert_eq!(t.expr().map(|x| 2 * x).par_eval(), view![[2, 4], [6, 8]]);
+    /// ```
+    fn par_eval(self) -> Tensor<Self::Item, Self::Shape>
+    where
+        Self::Item: Send,
+    {
+        let mut tensor = Tensor::uninit(self.shape().clone());
+
+        tensor.expr_mut().zip(self).par_for_each(|(x, y)| _ = x.write(y));
+
+        unsafe { tensor.assume_init() }
+    }
+
+    /// Folds all elements in parallel, and returns the result.
+    ///
+    /// Each part is folded into an accumulator created with `identity`, and the
+    /// accumulators are then combined with `reduce_op` in an unspecified order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::expr::{Expression, ParExpression};
+    /// use mdarray::tensor;
+    ///
+    /// let t = tensor![[1, 2], [3, 4]];
+    ///
+    /// assert_eq!(t.expr().par_fold(|| 0, |acc, x| acc + x, |a, b| a + b), 10);
+    /// ```
+    fn par_fold<T: Send, I, F, R>(self, identity: I, fold_op: F, reduce_op: R) -> T
+    where
+        Self::Item: Send,
+        I: Fn() -> T + Send + Sync,
+        F: Fn(T, Self::Item) -> T + Send + Sync,
+        R: Fn(T, T) -> T + Send + Sync,
+    {
+        ParParts::new(self).fold(&identity, fold_op).reduce(&identity, reduce_op)
+    }
+
+    /// Calls a closure on each element of the expression in parallel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::expr::{Expression, ParExpression};
+    /// use mdarray::{tensor, view};
+    ///
+    /// let mut t = tensor![[1, 2], [3, 4]];
+    ///
+    /// t.expr_mut().par_for_each(|x| *x *= 2);
+    ///
+    /// assert_eq!(t, view![[2, 4], [6, 8]]);
+    /// ```
+    fn par_for_each<F: Fn(Self::Item) + Send + Sync>(self, f: F)
+    where
+        Self::Item: Send,
+    {
+        ParParts::new(self).for_each(f);
+    }
+
+    /// Reduces all elements in parallel with an associative operation, and returns the
+    /// result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::expr::{Expression, ParExpression};
+    /// use mdarray::tensor;
+    ///
+    /// let t = tensor![[1, 2], [3, 4]];
+    ///
+    /// assert_eq!(t.expr().copied().par_reduce(|| 1, |a, b| a * b), 24);
+    /// ```
+    fn par_reduce<I, R>(self, identity: I, op: R) -> Self::Item
+    where
+        Self::Item: Send,
+        I: Fn() -> Self::Item + Send + Sync,
+        R: Fn(Self::Item, Self::Item) -> Self::Item + Send + Sync,
+    {
+        ParParts::new(self).reduce(identity, op)
+    }
+
+    #[doc(hidden)]
+    fn clone_part(&self) -> Self::Part;
+
+    #[doc(hidden)]
+    fn into_part(self) -> Self::Part;
+
+    #[doc(hidden)]
+    fn split_part(self, mid: usize) -> (Self::Part, Self::Part);
+}
+
+// Parallel iterator over the elements of an expression, which is split recursively
+// along the outermost dimension until the parts have size one in that dimension.
+struct ParParts<P> {
+    part: P,
+}
+
+impl<P: ParExpression<Part = P>> ParParts<P> {
+    fn new<E: ParExpression<Part = P>>(expr: E) -> Self {
+        Self { part: expr.into_part() }
+    }
+}
+
+impl<P: ParExpression<Part = P, Item: Send>> ParallelIterator for ParParts<P> {
+    type Item = P::Item;
+
+    fn drive_unindexed<C: UnindexedConsumer<P::Item>>(self, consumer: C) -> C::Result {
+        bridge_unindexed(self, consumer)
+    }
+}
+
+impl<P: ParExpression<Part = P>> UnindexedProducer for ParParts<P> {
+    type Item = P::Item;
+
+    fn split(self) -> (Self, Option<Self>) {
+        if self.part.rank() > 0 && self.part.dim(0) > 1 {
+            let mid = self.part.dim(0) / 2;
+            let (first, second) = self.part.split_part(mid);
+
+            (Self { part: first }, Some(Self { part: second }))
+        } else {
+            (self, None)
+        }
+    }
+
+    fn fold_with<F: Folder<P::Item>>(self, folder: F) -> F {
+        folder.consume_iter(self.part)
+    }
+}
+
+macro_rules! impl_into_par_iter {
+    ($name:tt, $bound:tt) => {
+        impl<'a, T: $bound, S: Shape, L: Layout, A: Axis> IntoParallelIterator
+            for $name<'a, T, S, L, A>
+        {
+            type Item = <Self as IntoIterator>::Item;
+            type Iter = IntoIter<Self::Item>;
+
+            fn into_par_iter(self) -> Self::Iter {
+                self.into_iter().collect::<Vec<_>>().into_par_iter()
+            }
+        }
+    };
+}
+
+impl_into_par_iter!(AxisExpr, Sync);
+impl_into_par_iter!(AxisExprMut, Send);
+impl_into_par_iter!(Lanes, Sync);
+impl_into_par_iter!(LanesMut, Send);
@@ -2,9 +2,11 @@ use core::fmt::{Debug, Formatter, Result};
 
 use crate::expr::expression::Expression;
 use crate::expr::iter::Iter;
+#[cfg(feature = "rayon")]
+use crate::expr::par::ParExpression;
 use crate::index::{Axis, Keep, Split};
-use crate::layout::Layout;
-use crate::mapping::Mapping;
+use crate::layout::{Layout, Strided};
+use crate::mapping::{Mapping, StridedMapping};
 use crate::shape::{IntoShape, Shape};
 use crate::slice::Slice;
 use crate::view::{View, ViewMut};
@@ -25,6 +27,42 @@ pub struct AxisExprMut<'a, T, S: Shape, L: Layout, A: Axis> {
     offset: isize,
 }
 
+/// Expression that gives non-overlapping array views, where the views at the end of
+/// each dimension can be smaller.
+pub struct Chunks<'a, T, S: Shape, L: Layout, W: Shape> {
+    slice: &'a Slice<T, S, L>,
+    mapping: StridedMapping<S::Dyn>,
+    inner: StridedMapping<W::Dyn>,
+    index: S::Dims<usize>,
+    offset: isize,
+}
+
+/// Mutable expression that gives non-overlapping array views, where the views at the end
+/// of each dimension can be smaller.
+pub struct ChunksMut<'a, T, S: Shape, L: Layout, W: Shape> {
+    slice: &'a mut Slice<T, S, L>,
+    mapping: StridedMapping<S::Dyn>,
+    inner: StridedMapping<W::Dyn>,
+    index: S::Dims<usize>,
+    offset: isize,
+}
+
+/// Expression that gives non-overlapping array views of equal size.
+pub struct ChunksExact<'a, T, S: Shape, L: Layout, W: Shape> {
+    slice: &'a Slice<T, S, L>,
+    mapping: StridedMapping<S::Dyn>,
+    inner: StridedMapping<W>,
+    offset: isize,
+}
+
+/// Mutable expression that gives non-overlapping array views of equal size.
+pub struct ChunksExactMut<'a, T, S: Shape, L: Layout, W: Shape> {
+    slice: &'a mut Slice<T, S, L>,
+    mapping: StridedMapping<S::Dyn>,
+    inner: StridedMapping<W>,
+    offset: isize,
+}
+
 /// Expression that repeats an element by cloning.
 #[derive(Clone)]
 pub struct Fill<T> {
@@ -68,6 +106,14 @@ pub struct LanesMut<'a, T, S: Shape, L: Layout, A: Axis> {
     offset: isize,
 }
 
+/// Expression that gives overlapping array views of equal size.
+pub struct Windows<'a, T, S: Shape, L: Layout, W: Shape> {
+    slice: &'a Slice<T, S, L>,
+    mapping: StridedMapping<S::Dyn>,
+    inner: StridedMapping<W>,
+    offset: isize,
+}
+
 /// Creates an expression with elements by cloning `value`.
 ///
 /// # Examples
@@ -218,6 +264,213 @@ impl<T, S: Shape, L: Layout, A: Axis> Clone for AxisExpr<'_, T, S, L, A> {
     }
 }
 
+macro_rules! impl_chunks {
+    ($name:tt, $expr:tt, $as_ptr:tt, {$($mut:tt)?}, $repeatable:tt) => {
+        impl<'a, T, S: Shape, L: Layout, W: Shape> $name<'a, T, S, L, W> {
+            pub(crate) fn new(slice: &'a $($mut)? Slice<T, S, L>, size: W) -> Self {
+                let size = size.with_dims(<W::Dyn as Shape>::from_dims);
+                let (mapping, inner) = mappings(slice, size, |dim, size, stride| {
+                    assert!(size > 0, "chunk size must be non-zero");
+
+                    (dim.div_ceil(size), if dim <= size { 0 } else { stride * size as isize })
+                });
+
+                let index = slice.shape().new_dims();
+
+                Self { slice, mapping, inner, index, offset: 0 }
+            }
+        }
+
+        impl<'a, T: Debug, S: Shape, L: Layout, W: Shape> Debug for $name<'a, T, S, L, W> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+                f.debug_tuple(stringify!($name))
+                    .field(self.inner.shape())
+                    .field(&self.slice)
+                    .finish()
+            }
+        }
+
+        impl<'a, T, S: Shape, L: Layout, W: Shape> Expression for $name<'a, T, S, L, W> {
+            type Shape = S::Dyn;
+
+            const IS_REPEATABLE: bool = $repeatable;
+
+            fn shape(&self) -> &Self::Shape {
+                self.mapping.shape()
+            }
+
+            unsafe fn get_unchecked(&mut self, index: usize) -> Self::Item {
+                let offset = self.offset + self.mapping.inner_stride() * index as isize;
+                let rank = self.mapping.rank();
+
+                let mut shape = self.inner.shape().clone();
+
+                // Views at the end of each dimension are limited to the remaining elements.
+                shape.with_mut_dims(|dims| {
+                    for i in 0..rank {
+                        let pos = self.index.as_ref()[i] + if i + 1 == rank { index } else { 0 };
+
+                        dims[i] = dims[i].min(self.slice.dim(i) - pos * dims[i]);
+                    }
+                });
+
+                let mapping = StridedMapping::new(shape, self.inner.strides());
+
+                // If the view is empty, we must not offset the pointer.
+                let count = if mapping.is_empty() { 0 } else { offset };
+
+                unsafe { $expr::new_unchecked(self.slice.$as_ptr().offset(count), mapping) }
+            }
+
+            fn inner_rank(&self) -> usize {
+                // For rank 0 or if there are no chunks, iterate over all dimensions at once
+                // so that no chunks are created outside of the array.
+                if self.mapping.rank() > 0 && !self.mapping.is_empty() { 1 } else { usize::MAX }
+            }
+
+            unsafe fn reset_dim(&mut self, index: usize, count: usize) {
+                self.index.as_mut()[index] -= count;
+                self.offset -= self.mapping.stride(index) * count as isize;
+            }
+
+            unsafe fn step_dim(&mut self, index: usize) {
+                self.index.as_mut()[index] += 1;
+                self.offset += self.mapping.stride(index);
+            }
+        }
+
+        impl<'a, T, S: Shape, L: Layout, W: Shape> IntoIterator for $name<'a, T, S, L, W> {
+            type Item = $expr<'a, T, W::Dyn, Strided>;
+            type IntoIter = Iter<Self>;
+
+            fn into_iter(self) -> Iter<Self> {
+                Iter::new(self)
+            }
+        }
+    };
+}
+
+impl_chunks!(Chunks, View, as_ptr, {}, true);
+impl_chunks!(ChunksMut, ViewMut, as_mut_ptr, {mut}, false);
+
+impl<T, S: Shape, L: Layout, W: Shape> Clone for Chunks<'_, T, S, L, W> {
+    fn clone(&self) -> Self {
+        Self {
+            slice: self.slice,
+            mapping: self.mapping.clone(),
+            inner: self.inner.clone(),
+            index: self.index.clone(),
+            offset: self.offset,
+        }
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.slice = source.slice;
+        self.mapping.clone_from(&source.mapping);
+        self.inner.clone_from(&source.inner);
+        self.index.clone_from(&source.index);
+        self.offset = source.offset;
+    }
+}
+
+macro_rules! impl_tiles {
+    ($name:tt, $expr:tt, $as_ptr:tt, {$($mut:tt)?}, $repeatable:tt) => {
+        impl<'a, T: Debug, S: Shape, L: Layout, W: Shape> Debug for $name<'a, T, S, L, W> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+                f.debug_tuple(stringify!($name))
+                    .field(self.inner.shape())
+                    .field(&self.slice)
+                    .finish()
+            }
+        }
+
+        impl<'a, T, S: Shape, L: Layout, W: Shape> Expression for $name<'a, T, S, L, W> {
+            type Shape = S::Dyn;
+
+            const IS_REPEATABLE: bool = $repeatable;
+
+            fn shape(&self) -> &Self::Shape {
+                self.mapping.shape()
+            }
+
+            unsafe fn get_unchecked(&mut self, index: usize) -> Self::Item {
+                let offset = self.offset + self.mapping.inner_stride() * index as isize;
+
+                // If the view is empty, we must not offset the pointer.
+                let count = if self.inner.is_empty() { 0 } else { offset };
+
+                unsafe {
+                    $expr::new_unchecked(self.slice.$as_ptr().offset(count), self.inner.clone())
+                }
+            }
+
+            fn inner_rank(&self) -> usize {
+                // For rank 0 or if there are no tiles, iterate over all dimensions at once
+                // so that no tiles are created outside of the array.
+                if self.mapping.rank() > 0 && !self.mapping.is_empty() { 1 } else { usize::MAX }
+            }
+
+            unsafe fn reset_dim(&mut self, index: usize, count: usize) {
+                self.offset -= self.mapping.stride(index) * count as isize;
+            }
+
+            unsafe fn step_dim(&mut self, index: usize) {
+                self.offset += self.mapping.stride(index);
+            }
+        }
+
+        impl<'a, T, S: Shape, L: Layout, W: Shape> IntoIterator for $name<'a, T, S, L, W> {
+            type Item = $expr<'a, T, W, Strided>;
+            type IntoIter = Iter<Self>;
+
+            fn into_iter(self) -> Iter<Self> {
+                Iter::new(self)
+            }
+        }
+    };
+}
+
+impl_tiles!(ChunksExact, View, as_ptr, {}, true);
+impl_tiles!(ChunksExactMut, ViewMut, as_mut_ptr, {mut}, false);
+impl_tiles!(Windows, View, as_ptr, {}, true);
+
+macro_rules! impl_chunks_exact {
+    ($name:tt, {$($mut:tt)?}) => {
+        impl<'a, T, S: Shape, L: Layout, W: Shape> $name<'a, T, S, L, W> {
+            pub(crate) fn new(slice: &'a $($mut)? Slice<T, S, L>, size: W) -> Self {
+                let (mapping, inner) = mappings(slice, size, |dim, size, stride| {
+                    assert!(size > 0, "chunk size must be non-zero");
+
+                    (dim / size, if dim < size { 0 } else { stride * size as isize })
+                });
+
+                Self { slice, mapping, inner, offset: 0 }
+            }
+        }
+    };
+}
+
+impl_chunks_exact!(ChunksExact, {});
+impl_chunks_exact!(ChunksExactMut, {mut});
+
+impl<T, S: Shape, L: Layout, W: Shape> Clone for ChunksExact<'_, T, S, L, W> {
+    fn clone(&self) -> Self {
+        Self {
+            slice: self.slice,
+            mapping: self.mapping.clone(),
+            inner: self.inner.clone(),
+            offset: self.offset,
+        }
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.slice = source.slice;
+        self.mapping.clone_from(&source.mapping);
+        self.inner.clone_from(&source.inner);
+        self.offset = source.offset;
+    }
+}
+
 impl<T> Fill<T> {
     pub(crate) fn new(value: T) -> Self {
         Self { value }
@@ -260,6 +513,23 @@ impl<T: Clone> IntoIterator for Fill<T> {
     }
 }
 
+#[cfg(feature = "rayon")]
+impl<T: Clone + Send> ParExpression for Fill<T> {
+    type Part = Self;
+
+    fn clone_part(&self) -> Self {
+        self.clone()
+    }
+
+    fn into_part(self) -> Self {
+        self
+    }
+
+    fn split_part(self, _: usize) -> (Self, Self) {
+        (self.clone(), self)
+    }
+}
+
 impl<F> FillWith<F> {
     pub(crate) fn new(f: F) -> Self {
         Self { f }
@@ -302,6 +572,23 @@ impl<T, F: FnMut() -> T> IntoIterator for FillWith<F> {
     }
 }
 
+#[cfg(feature = "rayon")]
+impl<T, F: Clone + FnMut() -> T + Send> ParExpression for FillWith<F> {
+    type Part = Self;
+
+    fn clone_part(&self) -> Self {
+        self.clone()
+    }
+
+    fn into_part(self) -> Self {
+        self
+    }
+
+    fn split_part(self, _: usize) -> (Self, Self) {
+        (self.clone(), self)
+    }
+}
+
 impl<T, S: Shape> FromElem<T, S> {
     pub(crate) fn new(shape: S, elem: T) -> Self {
         _ = shape.checked_len().expect("invalid length");
@@ -346,6 +633,29 @@ impl<T: Clone, S: Shape> IntoIterator for FromElem<T, S> {
     }
 }
 
+#[cfg(feature = "rayon")]
+impl<T: Clone + Send, S: Shape> ParExpression for FromElem<T, S> {
+    type Part = FromElem<T, S::Dyn>;
+
+    fn clone_part(&self) -> Self::Part {
+        self.clone().into_part()
+    }
+
+    fn into_part(self) -> Self::Part {
+        FromElem { shape: self.shape.with_dims(Shape::from_dims), elem: self.elem }
+    }
+
+    fn split_part(self, mid: usize) -> (Self::Part, Self::Part) {
+        let first = self.shape.resize_dim(0, mid);
+        let second = self.shape.resize_dim(0, self.shape.dim(0) - mid);
+
+        (
+            FromElem { shape: first, elem: self.elem.clone() },
+            FromElem { shape: second, elem: self.elem },
+        )
+    }
+}
+
 impl<S: Shape, F> FromFn<S, F> {
     pub(crate) fn new(shape: S, f: F) -> Self {
         _ = shape.checked_len().expect("invalid length");
@@ -372,8 +682,9 @@ impl<T, S: Shape, F: FnMut(&[usize]) -> T> Expression for FromFn<S, F> {
     unsafe fn get_unchecked(&mut self, _: usize) -> T {
         let value = (self.f)(self.index.as_ref());
 
-        // Increment the last dimension, which will be reset by reset_dim().
-        if self.rank() > 0 {
+        // Increment the last dimension, which will be reset by reset_dim(). If the size
+        // is one, it is not incremented since the dimension might be broadcast.
+        if self.rank() > 0 && self.shape.dim(self.rank() - 1) > 1 {
             self.index.as_mut()[self.shape.rank() - 1] += 1;
         }
 
@@ -405,6 +716,38 @@ impl<T, S: Shape, F: FnMut(&[usize]) -> T> IntoIterator for FromFn<S, F> {
     }
 }
 
+#[cfg(feature = "rayon")]
+impl<T, S: Shape, F: Clone + FnMut(&[usize]) -> T + Send> ParExpression for FromFn<S, F> {
+    type Part = FromFn<S::Dyn, F>;
+
+    fn clone_part(&self) -> Self::Part {
+        self.clone().into_part()
+    }
+
+    fn into_part(self) -> Self::Part {
+        let shape: S::Dyn = self.shape.with_dims(Shape::from_dims);
+        let mut index = shape.new_dims();
+
+        index.as_mut().copy_from_slice(self.index.as_ref());
+
+        FromFn { shape, f: self.f, index }
+    }
+
+    fn split_part(self, mid: usize) -> (Self::Part, Self::Part) {
+        let size = self.shape.dim(0);
+        let mut first = self.into_part();
+
+        // The second part starts at the offset in the first dimension.
+        let mut second = first.clone();
+
+        first.shape = first.shape.resize_dim(0, mid);
+        second.shape = second.shape.resize_dim(0, size - mid);
+        second.index.as_mut()[0] += mid;
+
+        (first, second)
+    }
+}
+
 macro_rules! impl_lanes {
     ($name:tt, $expr:tt, $as_ptr:tt, {$($mut:tt)?}, $repeatable:tt) => {
         impl<'a, T, S: Shape, L: Layout, A: Axis> $name<'a, T, S, L, A> {
@@ -439,7 +782,7 @@ macro_rules! impl_lanes {
             }
 
             unsafe fn get_unchecked(&mut self, index: usize) -> Self::Item {
-                let offset = self.mapping.inner_stride() * index as isize;
+                let offset = self.offset + self.mapping.inner_stride() * index as isize;
                 let mapping = self.axis.get(self.slice.mapping());
 
                 // If the view is empty, we must not offset the pointer.
@@ -498,3 +841,69 @@ impl<T, S: Shape, L: Layout, A: Axis> Clone for Lanes<'_, T, S, L, A> {
         self.offset = source.offset;
     }
 }
+
+impl<'a, T, S: Shape, L: Layout, W: Shape> Windows<'a, T, S, L, W> {
+    pub(crate) fn new(slice: &'a Slice<T, S, L>, size: W) -> Self {
+        let (mapping, inner) = mappings(slice, size, |dim, size, stride| {
+            assert!(size > 0, "window size must be non-zero");
+
+            (dim.saturating_sub(size - 1), stride)
+        });
+
+        Self { slice, mapping, inner, offset: 0 }
+    }
+}
+
+impl<T, S: Shape, L: Layout, W: Shape> Clone for Windows<'_, T, S, L, W> {
+    fn clone(&self) -> Self {
+        Self {
+            slice: self.slice,
+            mapping: self.mapping.clone(),
+            inner: self.inner.clone(),
+            offset: self.offset,
+        }
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.slice = source.slice;
+        self.mapping.clone_from(&source.mapping);
+        self.inner.clone_from(&source.inner);
+        self.offset = source.offset;
+    }
+}
+
+// Returns the mappings for the outer dimensions and for each array view in chunks or
+// windows, where the closure gives the outer size and stride from the size, the size of
+// each array view and the stride.
+fn mappings<T, S: Shape, L: Layout, W: Shape, F>(
+    slice: &Slice<T, S, L>,
+    size: W,
+    mut f: F,
+) -> (StridedMapping<S::Dyn>, StridedMapping<W>)
+where
+    F: FnMut(usize, usize, isize) -> (usize, isize),
+{
+    assert!(size.rank() == slice.rank(), "invalid rank");
+
+    let mut shape = S::Dyn::new(slice.rank());
+    let mut outer_strides = shape.new_dims::<isize>();
+    let mut inner_strides = size.new_dims::<isize>();
+
+    slice.shape().with_dims(|dims| {
+        size.with_dims(|sizes| {
+            shape.with_mut_dims(|outer| {
+                for i in 0..dims.len() {
+                    let stride = slice.stride(i);
+
+                    (outer[i], outer_strides.as_mut()[i]) = f(dims[i], sizes[i], stride);
+                    inner_strides.as_mut()[i] = stride;
+                }
+            });
+        });
+    });
+
+    let outer = StridedMapping::new(shape, outer_strides.as_ref());
+    let inner = StridedMapping::new(size, inner_strides.as_ref());
+
+    (outer, inner)
+}
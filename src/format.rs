@@ -0,0 +1,250 @@
+//! Formatting module, for printing arrays with `Display`.
+
+#[cfg(feature = "nightly")]
+use core::alloc::Allocator;
+use core::fmt::{self, Alignment, Formatter, Write};
+use core::iter;
+
+#[cfg(not(feature = "nightly"))]
+use crate::alloc::Allocator;
+use crate::array::Array;
+use crate::layout::Layout;
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use crate::shape::{ConstShape, Shape};
+use crate::slice::Slice;
+use crate::tensor::Tensor;
+use crate::view::{View, ViewMut};
+
+/// Array wrapper for printing with `Display` using the given options.
+///
+/// This struct is created by [`Slice::display`].
+pub struct Display<'a, T, S: Shape, L: Layout> {
+    slice: &'a Slice<T, S, L>,
+    options: PrintOptions,
+}
+
+/// Options for printing arrays with `Display`.
+///
+/// Arrays with more elements than the threshold are summarized, so that only the first
+/// and last elements are printed in each dimension, and the rest is replaced by `...`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct PrintOptions {
+    /// Total number of elements above which the array is summarized, default 1000.
+    pub threshold: usize,
+    /// Number of elements at the beginning and end of each dimension in a summarized
+    /// array, default 3.
+    pub edge_items: usize,
+    /// Number of characters per line before elements are wrapped, default 75.
+    pub line_width: usize,
+}
+
+impl<'a, T, S: Shape, L: Layout> Display<'a, T, S, L> {
+    pub(crate) fn new(slice: &'a Slice<T, S, L>, options: PrintOptions) -> Self {
+        Self { slice, options }
+    }
+}
+
+impl<T: fmt::Display, S: Shape, L: Layout> fmt::Display for Display<'_, T, S, L> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let slice = self.slice;
+
+        if slice.rank() == 0 {
+            return fmt::Display::fmt(&slice[[]], f);
+        }
+
+        let printer = Printer::new(slice, &self.options, f);
+
+        slice.shape().with_dims(|dims| printer.write_dim(f, dims, 0, &mut printer.elems.iter()))
+    }
+}
+
+impl PrintOptions {
+    /// Creates print options with the default values.
+    pub const fn new() -> Self {
+        Self { threshold: 1000, edge_items: 3, line_width: 75 }
+    }
+}
+
+impl Default for PrintOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Helper for printing, which holds the formatted elements in printing order.
+struct Printer {
+    elems: Vec<String>,
+    width: usize,
+    edge_items: Option<usize>,
+    line_width: usize,
+}
+
+impl Printer {
+    fn new<T: fmt::Display, S: Shape, L: Layout>(
+        slice: &Slice<T, S, L>,
+        options: &PrintOptions,
+        f: &Formatter<'_>,
+    ) -> Self {
+        let edge_items = (slice.len() > options.threshold).then_some(options.edge_items);
+
+        let mut printer =
+            Self { elems: Vec::new(), width: 0, edge_items, line_width: options.line_width };
+
+        let mut index = vec![0; slice.rank()];
+
+        slice.shape().with_dims(|dims| printer.format_dim(slice, f, dims, 0, &mut index));
+
+        // Align all elements to the same width, which is at least the given width.
+        let width = printer.elems.iter().map(|x| x.chars().count()).max().unwrap_or(0);
+
+        printer.width = width.max(f.width().unwrap_or(0));
+        printer
+    }
+
+    fn format_dim<T: fmt::Display, S: Shape, L: Layout>(
+        &mut self,
+        slice: &Slice<T, S, L>,
+        f: &Formatter<'_>,
+        dims: &[usize],
+        dim: usize,
+        index: &mut [usize],
+    ) {
+        for i in items(dims[dim], self.edge_items).flatten() {
+            index[dim] = i;
+
+            if dim + 1 < dims.len() {
+                self.format_dim(slice, f, dims, dim + 1, index);
+            } else {
+                let x = &slice[&*index];
+                let mut elem = String::new();
+
+                // Writing to a string can only fail if the element formatting fails,
+                // which is then ignored.
+                _ = match (f.precision(), f.sign_plus()) {
+                    (Some(prec), false) => write!(elem, "{x:.prec$}"),
+                    (Some(prec), true) => write!(elem, "{x:+.prec$}"),
+                    (None, false) => write!(elem, "{x}"),
+                    (None, true) => write!(elem, "{x:+}"),
+                };
+
+                self.elems.push(elem);
+            }
+        }
+    }
+
+    fn write_dim<'a>(
+        &self,
+        f: &mut Formatter<'_>,
+        dims: &[usize],
+        dim: usize,
+        elems: &mut impl Iterator<Item = &'a String>,
+    ) -> fmt::Result {
+        f.write_char('[')?;
+
+        if dim + 1 < dims.len() {
+            for (i, item) in items(dims[dim], self.edge_items).enumerate() {
+                if i > 0 {
+                    // Subarrays are separated by one line break per inner dimension.
+                    write_fill(f, '\n', dims.len() - dim - 1)?;
+                    write_fill(f, ' ', dim + 1)?;
+                }
+
+                match item {
+                    Some(_) => self.write_dim(f, dims, dim + 1, elems)?,
+                    None => f.write_str("...")?,
+                }
+            }
+        } else {
+            let mut column = dim + 1;
+
+            for (i, item) in items(dims[dim], self.edge_items).enumerate() {
+                let width = if item.is_some() { self.width } else { 3 };
+
+                if i > 0 {
+                    if column + width + 1 > self.line_width {
+                        f.write_char('\n')?;
+                        write_fill(f, ' ', dim + 1)?;
+                        column = dim + 1;
+                    } else {
+                        f.write_char(' ')?;
+                        column += 1;
+                    }
+                }
+
+                match item {
+                    Some(_) => self.write_elem(f, elems.next().expect("element missing"))?,
+                    None => f.write_str("...")?,
+                }
+
+                column += width;
+            }
+        }
+
+        f.write_char(']')
+    }
+
+    fn write_elem(&self, f: &mut Formatter<'_>, elem: &str) -> fmt::Result {
+        let padding = self.width - elem.chars().count();
+
+        let (left, right) = match f.align() {
+            Some(Alignment::Left) => (0, padding),
+            Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+            Some(Alignment::Right) | None => (padding, 0),
+        };
+
+        write_fill(f, f.fill(), left)?;
+        f.write_str(elem)?;
+        write_fill(f, f.fill(), right)
+    }
+}
+
+// Returns the indices to be printed in a dimension, where `None` is the ellipsis.
+fn items(size: usize, edge_items: Option<usize>) -> impl Iterator<Item = Option<usize>> {
+    let (first, last) = match edge_items {
+        Some(n) if size > 2 * n => (0..n, Some(size - n..size)),
+        _ => (0..size, None),
+    };
+
+    let last = last.map(|x| iter::once(None).chain(x.map(Some)));
+
+    first.map(Some).chain(last.into_iter().flatten())
+}
+
+fn write_fill(f: &mut Formatter<'_>, c: char, count: usize) -> fmt::Result {
+    for _ in 0..count {
+        f.write_char(c)?;
+    }
+
+    Ok(())
+}
+
+impl<T: fmt::Display, S: ConstShape> fmt::Display for Array<T, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
+    }
+}
+
+impl<T: fmt::Display, S: Shape, L: Layout> fmt::Display for Slice<T, S, L> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.display(PrintOptions::new()), f)
+    }
+}
+
+impl<T: fmt::Display, S: Shape, A: Allocator> fmt::Display for Tensor<T, S, A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
+    }
+}
+
+impl<T: fmt::Display, S: Shape, L: Layout> fmt::Display for View<'_, T, S, L> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
+    }
+}
+
+impl<T: fmt::Display, S: Shape, L: Layout> fmt::Display for ViewMut<'_, T, S, L> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
+    }
+}
@@ -2,6 +2,7 @@ use core::fmt::Debug;
 use core::hash::Hash;
 
 use crate::dim::{Const, Dim, Dyn};
+use crate::error::Error;
 use crate::layout::Layout;
 use crate::mapping::{DenseMapping, Mapping};
 use crate::shape::{DynRank, Shape};
@@ -24,7 +25,16 @@ pub trait Axis: Copy + Debug + Default + Hash + Ord + Send + Sync {
     type Insert<D: Dim, S: Shape>: Shape;
 
     /// Returns the dimension index.
-    fn index(self, rank: usize) -> usize;
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension is out of bounds.
+    fn index(self, rank: usize) -> usize {
+        self.try_index(rank).unwrap_or_else(|_| panic!("invalid dimension"))
+    }
+
+    #[doc(hidden)]
+    fn try_index(self, rank: usize) -> Result<usize, Error>;
 
     #[doc(hidden)]
     fn get<M: Mapping>(
@@ -78,14 +88,15 @@ pub type Split<A, S, L> = <<A as Axis>::Init<S> as Shape>::Layout<L>;
 
 //
 // The tables below give the resulting layout depending on the rank and axis.
+// The inner layout is the same as L, except for DenseColMajor which gives Strided.
 //
 // Keep<A, S, L>:
 //
 // Rank \ Axis  0           1           2           ...         Dyn
 // -------------------------------------------------------------------------
-// 1            L           -           -           -           Strided
-// 2            Strided     L           -           -           Strided
-// 3            Strided     Strided     L           -           Strided
+// 1            L::Inner    -           -           -           Strided
+// 2            Strided     L::Inner    -           -           Strided
+// 3            Strided     Strided     L::Inner    -           Strided
 // ...
 // DynRank      Strided     Strided     Strided     ...         Strided
 //
@@ -93,11 +104,11 @@ pub type Split<A, S, L> = <<A as Axis>::Init<S> as Shape>::Layout<L>;
 //
 // Rank \ Axis  0           1           2           ...         Dyn
 // -------------------------------------------------------------------------
-// 1            L           -           -           -           Strided
-// 2            L           Strided     -           -           Strided
-// 3            L           Strided     Strided     -           Strided
+// 1            L::Inner    -           -           -           Strided
+// 2            L::Inner    Strided     -           -           Strided
+// 3            L::Inner    Strided     Strided     -           Strided
 // ...
-// DynRank      L           Strided     Strided     ...         Strided
+// DynRank      L::Inner    Strided     Strided     ...         Strided
 //
 
 impl Axis for Const<0> {
@@ -109,10 +120,12 @@ impl Axis for Const<0> {
     type Remove<S: Shape> = S::Tail;
     type Insert<D: Dim, S: Shape> = S::Prepend<D>;
 
-    fn index(self, rank: usize) -> usize {
-        assert!(rank > 0, "invalid dimension");
+    fn try_index(self, rank: usize) -> Result<usize, Error> {
+        if rank == 0 {
+            return Err(Error::RankMismatch);
+        }
 
-        0
+        Ok(0)
     }
 }
 
@@ -131,10 +144,12 @@ macro_rules! impl_axis {
                 type Insert<D: Dim, S: Shape> =
                     <<Const<$k> as Axis>::Insert<D, S::Tail> as Shape>::Prepend<S::Head>;
 
-                fn index(self, rank: usize) -> usize {
-                    assert!(rank > $n, "invalid dimension");
+                fn try_index(self, rank: usize) -> Result<usize, Error> {
+                    if rank <= $n {
+                        return Err(Error::RankMismatch);
+                    }
 
-                    $n
+                    Ok($n)
                 }
             }
         )*
@@ -155,10 +170,12 @@ macro_rules! impl_cols_rows {
             type Insert<D: Dim, S: Shape> =
                 <<Const<$n> as Axis>::Insert<D, S::Reverse> as Shape>::Reverse;
 
-            fn index(self, rank: usize) -> usize {
-                assert!(rank > $n, "invalid dimension");
+            fn try_index(self, rank: usize) -> Result<usize, Error> {
+                if rank <= $n {
+                    return Err(Error::RankMismatch);
+                }
 
-                rank - $n - 1
+                Ok(rank - $n - 1)
             }
         }
     };
@@ -176,9 +193,11 @@ impl Axis for Dyn {
     type Remove<S: Shape> = <S::Tail as Shape>::Dyn;
     type Insert<D: Dim, S: Shape> = <S::Dyn as Shape>::Prepend<Dyn>;
 
-    fn index(self, rank: usize) -> usize {
-        assert!(self < rank, "invalid dimension");
+    fn try_index(self, rank: usize) -> Result<usize, Error> {
+        if self >= rank {
+            return Err(Error::IndexOutOfBounds);
+        }
 
-        self
+        Ok(self)
     }
 }
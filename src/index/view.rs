@@ -163,7 +163,7 @@ impl<R: RangeBounds<usize>> DimIndex for StepRange<R, isize> {
 impl ViewIndex for () {
     type Shape<S: Shape> = ();
     type Layout<L: Layout> = L;
-    type Outer<L: Layout> = L;
+    type Outer<L: Layout> = L::Inner;
 
     const RANK: usize = 0;
 
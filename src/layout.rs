@@ -1,4 +1,4 @@
-use crate::mapping::{DenseMapping, Mapping, StridedMapping};
+use crate::mapping::{DenseColMajorMapping, DenseMapping, Mapping, StridedMapping};
 use crate::shape::Shape;
 
 /// Array memory layout trait.
@@ -8,11 +8,22 @@ pub trait Layout {
 
     /// True if the layout type is dense.
     const IS_DENSE: bool;
+
+    #[doc(hidden)]
+    type Inner: Layout<Inner = Self::Inner>;
 }
 
 /// Dense array layout type.
 pub struct Dense;
 
+/// Dense array layout type in column-major order.
+///
+/// Elements are stored contiguously with the first dimension varying fastest, which
+/// is the order used by Fortran and LAPACK. Only the dense layout in row-major order
+/// is considered dense when accessing elements, and this layout is otherwise treated
+/// the same way as a strided layout.
+pub struct DenseColMajor;
+
 /// Strided array layout type.
 pub struct Strided;
 
@@ -20,10 +31,23 @@ impl Layout for Dense {
     type Mapping<S: Shape> = DenseMapping<S>;
 
     const IS_DENSE: bool = true;
+
+    type Inner = Dense;
+}
+
+impl Layout for DenseColMajor {
+    type Mapping<S: Shape> = DenseColMajorMapping<S>;
+
+    const IS_DENSE: bool = false;
+
+    // Removing or indexing the outer dimensions gives gaps between elements.
+    type Inner = Strided;
 }
 
 impl Layout for Strided {
     type Mapping<S: Shape> = StridedMapping<S>;
 
     const IS_DENSE: bool = false;
+
+    type Inner = Strided;
 }
@@ -52,6 +52,15 @@
 //! The array elements are stored in row-major or C order, where the first
 //! dimension is the outermost one.
 //!
+//! For interoperability with Fortran and LAPACK, the `DenseColMajor` layout has
+//! elements stored contiguously in column-major order, where the first dimension
+//! is the innermost one. The `col_major` and `row_major` methods convert between
+//! the two dense layouts without copying, where the dimensions are reversed. Arrays
+//! to be used in column-major order can be created with the reversed shape using
+//! `from_elem_transposed`, `from_fn_transposed` and `zeros_transposed`.
+//! When indexing or removing dimensions in an array with `DenseColMajor` layout,
+//! the resulting array view will have `Strided` layout.
+//!
 //! ## Indexing and views
 //!
 //! Scalar indexing is done using the normal square-bracket index operator and
@@ -85,9 +94,20 @@
 //! expressions, such as `eval`, `for_each` and `map`. Two expressions can be
 //! merged to an expression of tuples with the `zip` method or free function.
 //!
-//! When merging expressions, if the rank differs the expression with the lower
-//! rank is broadcast into the larger shape by adding outer dimensions. It is not
-//! possible to broadcast mutable arrays or when moving elements out of an array.
+//! When merging expressions, the shapes are broadcast following the NumPy rules.
+//! If the rank differs, the expression with the lower rank is broadcast into the
+//! larger shape by adding outer dimensions. Dimensions of size one are then
+//! stretched to match the size of the other expression. It is not possible to
+//! broadcast mutable arrays or when moving elements out of an array.
+//!
+//! The resulting shape type merges each dimension pair, where constant size is
+//! preferred over dynamic. Because of this, a constant-sized dimension of size
+//! one can in general not be stretched, and dynamically-sized dimensions should
+//! be used for broadcasting, for example with `reshape([1, 3])`. Constant-sized
+//! dimensions of different sizes cannot be merged and give a compile-time error.
+//!
+//! An array can also be broadcast explicitly with `broadcast_to` and `into_broadcast`,
+//! which give an array view where repeated dimensions have zero stride.
 //!
 //! For multidimensional arrays, iteration over a single dimension can be done
 //! with `outer_expr`, `outer_expr_mut`, `axis_expr` and `axis_expr_mut`.
@@ -103,6 +123,7 @@
 //!
 //! If at least one of the inputs is an array that is passed by value, the
 //! operation is evaluated directly and the input array is reused for the result.
+//! The array must then have the same shape as the result after broadcasting.
 //! Otherwise, if all input parameters are array references or expressions, an
 //! expression is returned. In the latter case, the result may have a different
 //! element type.
@@ -165,17 +186,23 @@ pub(crate) mod prelude {
     pub(crate) use alloc_crate::borrow::ToOwned;
     pub(crate) use alloc_crate::boxed::Box;
     pub(crate) use alloc_crate::collections::TryReserveError;
+    pub(crate) use alloc_crate::string::String;
     pub(crate) use alloc_crate::vec;
     pub(crate) use alloc_crate::vec::Vec;
 }
+pub mod backend;
 pub mod expr;
+pub mod format;
 pub mod index;
 
 mod array;
+mod concat;
 mod dim;
+mod error;
 mod layout;
 mod macros;
 mod mapping;
+mod matmul;
 mod ops;
 mod raw_slice;
 mod raw_tensor;
@@ -185,6 +212,24 @@ mod tensor;
 mod traits;
 mod view;
 
+#[cfg(feature = "approx")]
+mod approx;
+
+#[cfg(feature = "arrow")]
+mod arrow;
+
+#[cfg(feature = "num-complex")]
+mod complex;
+
+#[cfg(feature = "dlpack")]
+pub mod dlpack;
+
+#[cfg(feature = "num-traits")]
+pub mod linalg;
+
+#[cfg(feature = "npy")]
+pub mod npy;
+
 #[cfg(feature = "serde")]
 mod serde;
 #[cfg(not(feature = "nightly"))]
@@ -198,12 +243,21 @@ mod alloc {
 }
 
 pub use array::Array;
+pub use concat::{concat, stack};
 pub use dim::{Const, Dim, Dyn};
-pub use layout::{Dense, Layout, Strided};
-pub use mapping::{DenseMapping, Mapping, StridedMapping};
+pub use error::Error;
+pub use layout::{Dense, DenseColMajor, Layout, Strided};
+pub use mapping::{DenseColMajorMapping, DenseMapping, Mapping, StridedMapping};
 pub use ops::{StepRange, step};
 pub use shape::{ConstShape, DynRank, IntoShape, Rank, Shape};
 pub use slice::{DSlice, Slice};
 pub use tensor::{DTensor, Tensor};
 pub use traits::{IntoCloned, Owned};
 pub use view::{DView, DViewMut, View, ViewMut};
+
+#[cfg(feature = "approx")]
+#[doc(hidden)]
+pub use self::approx::{
+    assert_abs_diff_eq as __assert_abs_diff_eq, assert_relative_eq as __assert_relative_eq,
+    assert_ulps_eq as __assert_ulps_eq,
+};
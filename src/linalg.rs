@@ -0,0 +1,446 @@
+//! Dense linear algebra for matrices.
+//!
+//! The functions take matrices as array slices of rank 2 with any layout, so that
+//! strided array views and transposed matrices can be used without copying. The
+//! algorithms are straightforward implementations intended for small and medium-sized
+//! matrices. For large matrices, an optimized LAPACK implementation is preferred.
+//!
+//! Right-hand sides can be given as vectors of rank 1 or as matrices of rank 2, and
+//! the solution has the same shape as the right-hand side.
+//!
+//! # Examples
+//!
+//! ```
+//! use mdarray::{linalg, tensor, view};
+//!
+//! let a = tensor![[1.0, 2.0], [2.0, 2.0]];
+//! let b = tensor![5.0, 6.0];
+//!
+//! assert_eq!(linalg::solve(&a, &b), Ok(tensor![1.0, 2.0]));
+//! assert_eq!(linalg::solve(&a.transpose(), &view![[3.0], [4.0]]), Ok(tensor![[1.0], [1.0]]));
+//! assert_eq!(linalg::det(&a), -2.0);
+//! ```
+
+use core::cmp::Ordering;
+use core::mem;
+
+use num_traits::Float;
+
+use crate::error::Error;
+use crate::expr::Expression;
+use crate::layout::Layout;
+use crate::shape::Shape;
+use crate::slice::{DSlice, Slice};
+use crate::tensor::{DTensor, Tensor};
+
+/// LU decomposition with partial pivoting of a square matrix.
+///
+/// The decomposition is `P * A = L * U`, where `P` is a permutation matrix, `L` is
+/// lower triangular with unit diagonal and `U` is upper triangular.
+#[derive(Clone, Debug)]
+pub struct Lu<T> {
+    lu: DTensor<T, 2>,
+    perm: Vec<usize>,
+    odd: bool,
+}
+
+impl<T: Float> Lu<T> {
+    /// Returns the determinant of the matrix.
+    pub fn det(&self) -> T {
+        let det = (0..self.lu.dim(0)).fold(T::one(), |acc, i| acc * self.lu[[i, i]]);
+
+        if self.odd { -det } else { det }
+    }
+
+    /// Returns the inverse of the matrix.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Singular` if the matrix is singular.
+    pub fn inv(&self) -> Result<DTensor<T, 2>, Error> {
+        self.solve(&Tensor::eye(self.lu.dim(0)))
+    }
+
+    /// Returns the lower triangular factor with unit diagonal.
+    pub fn l(&self) -> DTensor<T, 2> {
+        Tensor::from_fn(*self.lu.shape(), |i| match i[0].cmp(&i[1]) {
+            Ordering::Less => T::zero(),
+            Ordering::Equal => T::one(),
+            Ordering::Greater => self.lu[[i[0], i[1]]],
+        })
+    }
+
+    /// Returns the row permutation, where row `i` in `P * A` is row `perm[i]` in `A`.
+    pub fn perm(&self) -> &[usize] {
+        &self.perm
+    }
+
+    /// Solves `A * X = B` for the right-hand side `B`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Singular` if the matrix is singular.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rank of the right-hand side is not 1 or 2, or if the number of
+    /// rows is not matching the matrix.
+    pub fn solve<S: Shape, L: Layout>(&self, b: &Slice<T, S, L>) -> Result<Tensor<T, S>, Error> {
+        solve_with(b, self.lu.dim(0), |x| {
+            let rhs = x.to_tensor();
+
+            for (i, &p) in self.perm.iter().enumerate() {
+                x.at_mut(i).assign(rhs.at(p));
+            }
+
+            forward(&self.lu, x, true)?;
+            backward(&self.lu, x)
+        })
+    }
+
+    /// Returns the upper triangular factor.
+    pub fn u(&self) -> DTensor<T, 2> {
+        Tensor::from_fn(*self.lu.shape(), |i| {
+            if i[0] <= i[1] { self.lu[[i[0], i[1]]] } else { T::zero() }
+        })
+    }
+}
+
+/// Returns the Cholesky decomposition `A = L * L^T` of a symmetric positive definite
+/// matrix, where `L` is lower triangular.
+///
+/// Only the lower triangle of the matrix is used.
+///
+/// # Errors
+///
+/// Returns `Error::NotPositiveDefinite` if the matrix is not positive definite.
+///
+/// # Panics
+///
+/// Panics if the matrix is not square.
+pub fn cholesky<T: Float, L: Layout>(a: &DSlice<T, 2, L>) -> Result<DTensor<T, 2>, Error> {
+    assert!(a.dim(0) == a.dim(1), "matrix not square");
+
+    let mut l = a.to_tensor();
+
+    cholesky_in_place(&mut l)?;
+
+    Ok(Tensor::from_fn(*l.shape(), |i| if i[0] >= i[1] { l[[i[0], i[1]]] } else { T::zero() }))
+}
+
+/// Returns the determinant of a square matrix.
+///
+/// # Panics
+///
+/// Panics if the matrix is not square.
+pub fn det<T: Float, L: Layout>(a: &DSlice<T, 2, L>) -> T {
+    lu(a).det()
+}
+
+/// Returns the inverse of a square matrix.
+///
+/// # Errors
+///
+/// Returns `Error::Singular` if the matrix is singular.
+///
+/// # Panics
+///
+/// Panics if the matrix is not square.
+pub fn inv<T: Float, L: Layout>(a: &DSlice<T, 2, L>) -> Result<DTensor<T, 2>, Error> {
+    lu(a).inv()
+}
+
+/// Returns the LU decomposition with partial pivoting of a square matrix.
+///
+/// The decomposition is computed also for singular matrices, and the singularity is
+/// reported when solving.
+///
+/// # Panics
+///
+/// Panics if the matrix is not square.
+pub fn lu<T: Float, L: Layout>(a: &DSlice<T, 2, L>) -> Lu<T> {
+    assert!(a.dim(0) == a.dim(1), "matrix not square");
+
+    let n = a.dim(0);
+
+    let mut lu = a.to_tensor();
+    let mut ipiv = vec![0; n];
+
+    // Singular matrices are reported when solving.
+    _ = lu_in_place(&mut lu, &mut ipiv);
+
+    let mut perm = (0..n).collect::<Vec<_>>();
+    let mut odd = false;
+
+    for (k, &p) in ipiv.iter().enumerate() {
+        if p != k {
+            perm.swap(k, p);
+            odd = !odd;
+        }
+    }
+
+    Lu { lu, perm, odd }
+}
+
+/// Returns the QR decomposition `A = Q * R` of a matrix using Householder reflections.
+///
+/// For a matrix with `m` rows and `n` columns, the reduced decomposition is returned
+/// where `Q` has `min(m, n)` orthonormal columns and `R` is upper triangular with
+/// `min(m, n)` rows.
+///
+/// # Examples
+///
+/// ```
+/// use mdarray::{linalg, tensor};
+///
+/// let a = tensor![[3.0, 1.0], [4.0, 2.0], [0.0, 2.0f64]];
+/// let (q, r) = linalg::qr(&a);
+///
+/// assert_eq!(q.shape(), &(3, 2));
+/// assert_eq!(r[[1, 0]], 0.0);
+/// assert!((q.matmul(&r) - &a).iter().all(|x| x.abs() < 1e-12));
+/// ```
+pub fn qr<T: Float, L: Layout>(a: &DSlice<T, 2, L>) -> (DTensor<T, 2>, DTensor<T, 2>) {
+    let (m, n) = (a.dim(0), a.dim(1));
+    let k = m.min(n);
+
+    let mut r = a.to_tensor();
+    let mut v = Tensor::from_elem([k, m], T::zero());
+
+    for j in 0..k {
+        let norm = (j..m).fold(T::zero(), |acc, i| acc + r[[i, j]] * r[[i, j]]).sqrt();
+
+        // Skip the reflection if the column is already zero.
+        if norm == T::zero() {
+            continue;
+        }
+
+        // Reflect to the opposite sign of the diagonal element to avoid cancellation.
+        let alpha = if r[[j, j]] < T::zero() { norm } else { -norm };
+
+        for i in j..m {
+            v[[j, i]] = r[[i, j]];
+        }
+
+        v[[j, j]] = v[[j, j]] - alpha;
+
+        let scale = (j..m).fold(T::zero(), |acc, i| acc + v[[j, i]] * v[[j, i]]).sqrt();
+
+        v.view_mut(j, j..).expr_mut().for_each(|x| *x = *x / scale);
+
+        reflect(&v.view(j, j..), &mut r.view_mut(j.., j..));
+    }
+
+    let mut q = Tensor::from_fn([m, k], |i| if i[0] == i[1] { T::one() } else { T::zero() });
+
+    for j in (0..k).rev() {
+        reflect(&v.view(j, j..), &mut q.view_mut(j.., ..));
+    }
+
+    let r = Tensor::from_fn([k, n], |i| if i[0] <= i[1] { r[[i[0], i[1]]] } else { T::zero() });
+
+    (q, r)
+}
+
+/// Solves `A * X = B` for a square matrix `A` and the right-hand side `B`.
+///
+/// # Errors
+///
+/// Returns `Error::Singular` if the matrix is singular.
+///
+/// # Panics
+///
+/// Panics if the matrix is not square, if the rank of the right-hand side is not 1
+/// or 2, or if the number of rows is not matching the matrix.
+pub fn solve<T: Float, S: Shape, L: Layout, K: Layout>(
+    a: &DSlice<T, 2, L>,
+    b: &Slice<T, S, K>,
+) -> Result<Tensor<T, S>, Error> {
+    lu(a).solve(b)
+}
+
+/// Solves `A * X = B` by forward substitution, where `A` is lower triangular.
+///
+/// Only the lower triangle of the matrix is used.
+///
+/// # Errors
+///
+/// Returns `Error::Singular` if a diagonal element is zero.
+///
+/// # Panics
+///
+/// Panics if the matrix is not square, if the rank of the right-hand side is not 1
+/// or 2, or if the number of rows is not matching the matrix.
+pub fn solve_lower<T: Float, S: Shape, L: Layout, K: Layout>(
+    a: &DSlice<T, 2, L>,
+    b: &Slice<T, S, K>,
+) -> Result<Tensor<T, S>, Error> {
+    assert!(a.dim(0) == a.dim(1), "matrix not square");
+
+    solve_with(b, a.dim(0), |x| forward(a, x, false))
+}
+
+/// Solves `A * X = B` by back substitution, where `A` is upper triangular.
+///
+/// Only the upper triangle of the matrix is used.
+///
+/// # Errors
+///
+/// Returns `Error::Singular` if a diagonal element is zero.
+///
+/// # Panics
+///
+/// Panics if the matrix is not square, if the rank of the right-hand side is not 1
+/// or 2, or if the number of rows is not matching the matrix.
+pub fn solve_upper<T: Float, S: Shape, L: Layout, K: Layout>(
+    a: &DSlice<T, 2, L>,
+    b: &Slice<T, S, K>,
+) -> Result<Tensor<T, S>, Error> {
+    assert!(a.dim(0) == a.dim(1), "matrix not square");
+
+    solve_with(b, a.dim(0), |x| backward(a, x))
+}
+
+// Computes the Cholesky decomposition in place, where the lower triangle is replaced
+// with the factor and the upper triangle is not accessed.
+pub(crate) fn cholesky_in_place<T: Float, L: Layout>(a: &mut DSlice<T, 2, L>) -> Result<(), Error> {
+    for j in 0..a.dim(0) {
+        let d = (0..j).fold(a[[j, j]], |acc, k| acc - a[[j, k]] * a[[j, k]]);
+
+        // Compare with partial_cmp to also reject NaN.
+        if d.partial_cmp(&T::zero()) != Some(Ordering::Greater) {
+            return Err(Error::NotPositiveDefinite);
+        }
+
+        a[[j, j]] = d.sqrt();
+
+        for i in j + 1..a.dim(0) {
+            let s = (0..j).fold(a[[i, j]], |acc, k| acc - a[[i, k]] * a[[j, k]]);
+
+            a[[i, j]] = s / a[[j, j]];
+        }
+    }
+
+    Ok(())
+}
+
+// Computes the LU decomposition with partial pivoting in place for a matrix with any
+// number of rows and columns, where `ipiv` is set to the row interchanged with each row
+// in order. If a pivot is zero, the decomposition is completed and an error is returned.
+pub(crate) fn lu_in_place<T: Float, L: Layout>(
+    a: &mut DSlice<T, 2, L>,
+    ipiv: &mut [usize],
+) -> Result<(), Error> {
+    let (m, n) = (a.dim(0), a.dim(1));
+
+    assert!(ipiv.len() == m.min(n), "length mismatch");
+
+    let mut singular = false;
+
+    for k in 0..m.min(n) {
+        // Select the row with the largest absolute value in the column as pivot.
+        let p = (k + 1..m).fold(k, |p, i| if a[[i, k]].abs() > a[[p, k]].abs() { i } else { p });
+
+        if p != k {
+            let (mut top, mut bottom) = a.split_at_mut(p);
+
+            top.at_mut(k).expr_mut().zip(bottom.at_mut(0)).for_each(|(x, y)| mem::swap(x, y));
+        }
+
+        ipiv[k] = p;
+
+        let pivot = a[[k, k]];
+
+        if pivot == T::zero() {
+            singular = true;
+        } else {
+            for i in k + 1..m {
+                let f = a[[i, k]] / pivot;
+
+                a[[i, k]] = f;
+
+                for j in k + 1..n {
+                    a[[i, j]] = a[[i, j]] - f * a[[k, j]];
+                }
+            }
+        }
+    }
+
+    if singular { Err(Error::Singular) } else { Ok(()) }
+}
+
+// Solves `A * X = B` in place for an upper triangular matrix, using back substitution.
+fn backward<T: Float, L: Layout>(a: &DSlice<T, 2, L>, x: &mut DSlice<T, 2>) -> Result<(), Error> {
+    for i in (0..x.dim(0)).rev() {
+        let d = a[[i, i]];
+
+        if d == T::zero() {
+            return Err(Error::Singular);
+        }
+
+        for j in 0..x.dim(1) {
+            let s = (i + 1..x.dim(0)).fold(x[[i, j]], |acc, k| acc - a[[i, k]] * x[[k, j]]);
+
+            x[[i, j]] = s / d;
+        }
+    }
+
+    Ok(())
+}
+
+// Solves `A * X = B` in place for a lower triangular matrix, using forward substitution.
+// If `unit` is true, the diagonal elements are assumed to be one and are not read.
+fn forward<T: Float, L: Layout>(
+    a: &DSlice<T, 2, L>,
+    x: &mut DSlice<T, 2>,
+    unit: bool,
+) -> Result<(), Error> {
+    for i in 0..x.dim(0) {
+        let d = if unit { T::one() } else { a[[i, i]] };
+
+        if d == T::zero() {
+            return Err(Error::Singular);
+        }
+
+        for j in 0..x.dim(1) {
+            let s = (0..i).fold(x[[i, j]], |acc, k| acc - a[[i, k]] * x[[k, j]]);
+
+            x[[i, j]] = s / d;
+        }
+    }
+
+    Ok(())
+}
+
+// Applies the Householder reflection `I - 2 * v * v^T` with a unit vector `v` to
+// each column of the matrix.
+fn reflect<T: Float, L: Layout, K: Layout>(v: &DSlice<T, 1, L>, c: &mut DSlice<T, 2, K>) {
+    let two = T::one() + T::one();
+
+    c.cols_mut().for_each(|mut col| {
+        let s = col.iter().zip(v).fold(T::zero(), |acc, (&x, &y)| acc + x * y);
+
+        col.expr_mut().zip(v).for_each(|(x, &y)| *x = *x - two * s * y);
+    });
+}
+
+// Copies the right-hand side to a new array, and calls the function to solve in place
+// with the right-hand side as a matrix with one or more columns.
+fn solve_with<T: Float, S: Shape, L: Layout, F>(
+    b: &Slice<T, S, L>,
+    n: usize,
+    f: F,
+) -> Result<Tensor<T, S>, Error>
+where
+    F: FnOnce(&mut DSlice<T, 2>) -> Result<(), Error>,
+{
+    assert!(b.rank() == 1 || b.rank() == 2, "invalid rank");
+    assert!(b.dim(0) == n, "shape mismatch");
+
+    let cols = if b.rank() == 1 { 1 } else { b.dim(1) };
+    let mut x = b.to_tensor();
+
+    f(&mut x.reshape_mut([n, cols]))?;
+
+    Ok(x)
+}
@@ -1,7 +1,8 @@
 use core::fmt::Debug;
 use core::hash::Hash;
 
-use crate::layout::{Dense, Layout, Strided};
+use crate::error::Error;
+use crate::layout::{Dense, DenseColMajor, Layout, Strided};
 use crate::shape::{DynRank, Shape};
 
 /// Array layout mapping trait, including shape and strides.
@@ -73,13 +74,17 @@ pub trait Mapping: Clone + Debug + Default + Eq + Hash + Send + Sync {
     fn prepend_dim<M: Mapping>(mapping: &M, size: usize, stride: isize) -> Self;
 
     #[doc(hidden)]
-    fn remap<M: Mapping>(mapping: &M) -> Self;
+    fn remap<M: Mapping>(mapping: &M) -> Self {
+        Self::try_remap(mapping).unwrap_or_else(|err| panic!("{err}"))
+    }
 
     #[doc(hidden)]
     fn remove_dim<M: Mapping>(mapping: &M, index: usize) -> Self;
 
     #[doc(hidden)]
-    fn reshape<S: Shape>(&self, new_shape: S) -> <Self::Layout as Layout>::Mapping<S>;
+    fn reshape<S: Shape>(&self, new_shape: S) -> <Self::Layout as Layout>::Mapping<S> {
+        self.try_reshape(new_shape).unwrap_or_else(|err| panic!("{err}"))
+    }
 
     #[doc(hidden)]
     fn resize_dim<M: Mapping>(mapping: &M, index: usize, new_size: usize) -> Self;
@@ -90,6 +95,15 @@ pub trait Mapping: Clone + Debug + Default + Eq + Hash + Send + Sync {
     #[doc(hidden)]
     fn transpose<M: Mapping<Shape: Shape<Reverse = Self::Shape>>>(mapping: &M) -> Self;
 
+    #[doc(hidden)]
+    fn try_remap<M: Mapping>(mapping: &M) -> Result<Self, Error>;
+
+    #[doc(hidden)]
+    fn try_reshape<S: Shape>(
+        &self,
+        new_shape: S,
+    ) -> Result<<Self::Layout as Layout>::Mapping<S>, Error>;
+
     #[doc(hidden)]
     fn offset(&self, index: &[usize]) -> isize {
         debug_assert!(index.len() == self.rank(), "invalid rank");
@@ -112,6 +126,12 @@ pub struct DenseMapping<S: Shape> {
     shape: S,
 }
 
+/// Dense layout mapping type in column-major order.
+#[derive(Debug, Default, Eq, Hash, PartialEq)]
+pub struct DenseColMajorMapping<S: Shape> {
+    shape: S,
+}
+
 /// Strided layout mapping type.
 #[derive(Debug, Eq, Hash, PartialEq)]
 pub struct StridedMapping<S: Shape> {
@@ -200,12 +220,6 @@ impl<S: Shape> Mapping for DenseMapping<S> {
         Self::new(mapping.shape().prepend_dim(size))
     }
 
-    fn remap<M: Mapping>(mapping: &M) -> Self {
-        assert!(mapping.is_contiguous(), "mapping not contiguous");
-
-        Self::new(mapping.shape().with_dims(Shape::from_dims))
-    }
-
     fn remove_dim<M: Mapping>(mapping: &M, index: usize) -> Self {
         assert!(M::Layout::IS_DENSE, "invalid layout");
         assert!(index == 0, "invalid dimension");
@@ -213,10 +227,6 @@ impl<S: Shape> Mapping for DenseMapping<S> {
         Self::new(mapping.shape().remove_dim(index))
     }
 
-    fn reshape<R: Shape>(&self, new_shape: R) -> DenseMapping<R> {
-        DenseMapping::new(self.shape.reshape(new_shape))
-    }
-
     fn resize_dim<M: Mapping>(mapping: &M, index: usize, new_size: usize) -> Self {
         assert!(M::Layout::IS_DENSE, "invalid layout");
         assert!(index == 0, "invalid dimension");
@@ -229,10 +239,147 @@ impl<S: Shape> Mapping for DenseMapping<S> {
     }
 
     fn transpose<M: Mapping<Shape: Shape<Reverse = S>>>(mapping: &M) -> Self {
-        assert!(mapping.rank() < 2 && M::Layout::IS_DENSE, "invalid layout");
+        assert!(is_col_major(mapping), "invalid layout");
+
+        Self::new(mapping.shape().reverse())
+    }
+
+    fn try_remap<M: Mapping>(mapping: &M) -> Result<Self, Error> {
+        if !mapping.is_contiguous() {
+            return Err(Error::NotContiguous);
+        }
+
+        Ok(Self::new(mapping.shape().with_dims(Shape::try_from_dims)?))
+    }
+
+    fn try_reshape<R: Shape>(&self, new_shape: R) -> Result<DenseMapping<R>, Error> {
+        Ok(DenseMapping::new(self.shape.try_reshape(new_shape)?))
+    }
+}
+
+impl<S: Shape> DenseColMajorMapping<S> {
+    /// Creates a new, dense layout mapping in column-major order with the specified shape.
+    pub fn new(shape: S) -> Self {
+        Self { shape }
+    }
+}
+
+impl<S: Shape> Clone for DenseColMajorMapping<S> {
+    fn clone(&self) -> Self {
+        Self::new(self.shape.clone())
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.shape.clone_from(&source.shape);
+    }
+}
+
+impl<S: Shape + Copy> Copy for DenseColMajorMapping<S> {}
+
+impl<S: Shape> Mapping for DenseColMajorMapping<S> {
+    type Shape = S;
+    type Layout = DenseColMajor;
+
+    fn is_contiguous(&self) -> bool {
+        // The elements are contiguous in memory, but only in row-major order if
+        // there is at most one dimension with more than one element.
+        self.shape.with_dims(|dims| dims.iter().filter(|&&size| size > 1).count() < 2)
+    }
+
+    fn shape(&self) -> &S {
+        &self.shape
+    }
+
+    fn stride(&self, index: usize) -> isize {
+        assert!(index < self.rank(), "invalid dimension");
+
+        let mut stride = 1;
+
+        for i in 0..index {
+            stride *= self.dim(i);
+        }
+
+        stride as isize
+    }
+
+    fn for_each_stride<F: FnMut(usize, isize)>(&self, mut f: F) {
+        let mut stride = 1;
+
+        for i in 0..self.rank() {
+            f(i, stride as isize);
+            stride *= self.dim(i);
+        }
+    }
+
+    fn inner_stride(&self) -> isize {
+        if self.rank() > 0 { self.stride(self.rank() - 1) } else { 0 }
+    }
+
+    fn linear_offset(&self, index: usize) -> isize {
+        debug_assert!(index < self.len(), "index out of bounds");
+
+        let mut dividend = index;
+        let mut offset = 0;
+
+        for i in (0..self.rank()).rev() {
+            offset += self.stride(i) * (dividend % self.dim(i)) as isize;
+            dividend /= self.dim(i);
+        }
+
+        offset
+    }
+
+    fn permute<M: Mapping>(mapping: &M, perm: &[usize]) -> Self {
+        assert!(perm.len() == mapping.rank(), "invalid permutation");
+
+        for i in 0..mapping.rank() {
+            assert!(perm[i] == i, "invalid permutation");
+        }
+
+        Self::remap(mapping)
+    }
+
+    fn prepend_dim<M: Mapping>(mapping: &M, size: usize, stride: isize) -> Self {
+        Self::remap(&StridedMapping::<S>::prepend_dim(mapping, size, stride))
+    }
+
+    fn remove_dim<M: Mapping>(mapping: &M, index: usize) -> Self {
+        Self::remap(&StridedMapping::<S>::remove_dim(mapping, index))
+    }
+
+    fn resize_dim<M: Mapping>(mapping: &M, index: usize, new_size: usize) -> Self {
+        Self::remap(&StridedMapping::<S>::resize_dim(mapping, index, new_size))
+    }
+
+    fn shape_mut(&mut self) -> &mut S {
+        &mut self.shape
+    }
+
+    fn transpose<M: Mapping<Shape: Shape<Reverse = S>>>(mapping: &M) -> Self {
+        assert!(mapping.is_contiguous(), "invalid layout");
 
         Self::new(mapping.shape().reverse())
     }
+
+    fn try_remap<M: Mapping>(mapping: &M) -> Result<Self, Error> {
+        if !is_col_major(mapping) {
+            return Err(Error::NotContiguous);
+        }
+
+        Ok(Self::new(mapping.shape().with_dims(Shape::try_from_dims)?))
+    }
+
+    fn try_reshape<R: Shape>(&self, new_shape: R) -> Result<DenseColMajorMapping<R>, Error> {
+        // Elements are reshaped in row-major order, which keeps the layout only if
+        // the resulting strides are also in column-major order.
+        let mapping = StridedMapping::<S>::remap(self).try_reshape(new_shape)?;
+
+        if !is_col_major(&mapping) {
+            return Err(Error::NotContiguous);
+        }
+
+        Ok(DenseColMajorMapping::new(mapping.shape))
+    }
 }
 
 impl<S: Shape> StridedMapping<S> {
@@ -366,14 +513,6 @@ impl<S: Shape> Mapping for StridedMapping<S> {
         Self { shape, strides }
     }
 
-    fn remap<M: Mapping>(mapping: &M) -> Self {
-        let shape: S = mapping.shape().with_dims(Shape::from_dims);
-        let mut strides = shape.new_dims();
-        mapping.for_each_stride(|i, stride| strides.as_mut()[i] = stride);
-
-        Self { shape, strides }
-    }
-
     fn remove_dim<M: Mapping>(mapping: &M, index: usize) -> Self {
         assert!(index < mapping.rank(), "invalid dimension");
         let shape: S = mapping.shape().remove_dim(index);
@@ -390,8 +529,39 @@ impl<S: Shape> Mapping for StridedMapping<S> {
         Self { shape, strides }
     }
 
-    fn reshape<R: Shape>(&self, new_shape: R) -> StridedMapping<R> {
-        let new_shape = self.shape.reshape(new_shape);
+    fn resize_dim<M: Mapping>(mapping: &M, index: usize, new_size: usize) -> Self {
+        let shape: S = mapping.shape().resize_dim(index, new_size);
+        let mut strides = shape.new_dims();
+
+        mapping.for_each_stride(|i, stride| strides.as_mut()[i] = stride);
+
+        Self { shape, strides }
+    }
+
+    fn shape_mut(&mut self) -> &mut S {
+        &mut self.shape
+    }
+
+    fn transpose<M: Mapping<Shape: Shape<Reverse = S>>>(mapping: &M) -> Self {
+        let shape = mapping.shape().reverse();
+        let mut strides = shape.new_dims();
+
+        mapping.for_each_stride(|i, stride| strides.as_mut()[mapping.rank() - 1 - i] = stride);
+
+        Self { shape, strides }
+    }
+
+    fn try_remap<M: Mapping>(mapping: &M) -> Result<Self, Error> {
+        let shape: S = mapping.shape().with_dims(Shape::try_from_dims)?;
+        let mut strides = shape.new_dims();
+
+        mapping.for_each_stride(|i, stride| strides.as_mut()[i] = stride);
+
+        Ok(Self { shape, strides })
+    }
+
+    fn try_reshape<R: Shape>(&self, new_shape: R) -> Result<StridedMapping<R>, Error> {
+        let new_shape = self.shape.try_reshape(new_shape)?;
         let mut new_strides = new_shape.new_dims();
 
         let mut old_len = 1usize;
@@ -441,30 +611,25 @@ impl<S: Shape> Mapping for StridedMapping<S> {
             new_stride *= new_shape.dim(j) as isize;
         }
 
-        assert!(new_len == 0 || valid_layout, "memory layout not compatible");
+        if new_len > 0 && !valid_layout {
+            return Err(Error::NotContiguous);
+        }
 
-        StridedMapping { shape: new_shape, strides: new_strides }
+        Ok(StridedMapping { shape: new_shape, strides: new_strides })
     }
+}
 
-    fn resize_dim<M: Mapping>(mapping: &M, index: usize, new_size: usize) -> Self {
-        let shape: S = mapping.shape().resize_dim(index, new_size);
-        let mut strides = shape.new_dims();
-
-        mapping.for_each_stride(|i, stride| strides.as_mut()[i] = stride);
+fn is_col_major<M: Mapping>(mapping: &M) -> bool {
+    let mut stride = 1;
 
-        Self { shape, strides }
-    }
+    // The stride is not used for dimensions with a single element, so any value is valid.
+    for i in 0..mapping.rank() {
+        if mapping.dim(i) != 1 && mapping.stride(i) != stride {
+            return false;
+        }
 
-    fn shape_mut(&mut self) -> &mut S {
-        &mut self.shape
+        stride *= mapping.dim(i) as isize;
     }
 
-    fn transpose<M: Mapping<Shape: Shape<Reverse = S>>>(mapping: &M) -> Self {
-        let shape = mapping.shape().reverse();
-        let mut strides = shape.new_dims();
-
-        mapping.for_each_stride(|i, stride| strides.as_mut()[mapping.rank() - 1 - i] = stride);
-
-        Self { shape, strides }
-    }
+    true
 }
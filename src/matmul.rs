@@ -0,0 +1,267 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
+use core::ops::{Add, Mul};
+
+use crate::dim::Dim;
+use crate::layout::{Dense, Layout};
+use crate::shape::{DynRank, Shape};
+use crate::slice::{DSlice, Slice};
+use crate::tensor::Tensor;
+
+// Block sizes for the rows in the left matrix, the inner dimension and the columns
+// in the right matrix. The packed blocks are kept small enough to stay in cache.
+const MC: usize = 64;
+const KC: usize = 256;
+const NC: usize = 512;
+
+// Number of rows and columns in the register-blocked kernel.
+const MR: usize = 4;
+const NR: usize = 8;
+
+impl<T, X: Dim, Y: Dim, L: Layout> Slice<T, (X, Y), L> {
+    /// Returns the matrix product of two arrays of rank 2.
+    ///
+    /// Zero elements for the result are created using `Default::default()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::{tensor, view};
+    ///
+    /// let a = tensor![[1, 2, 3], [4, 5, 6]];
+    /// let b = tensor![[1, 0], [0, 1], [1, 1]];
+    ///
+    /// assert_eq!(a.matmul(&b), view![[4, 5], [10, 11]]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of columns in the left array is not equal to the number
+    /// of rows in the right array.
+    pub fn matmul<Z: Dim, W: Dim, K: Layout>(&self, rhs: &Slice<T, (Z, W), K>) -> Tensor<T, (X, W)>
+    where
+        T: Add<Output = T> + Copy + Default + Mul<Output = T>,
+    {
+        assert!(self.dim(1) == rhs.dim(0), "inner dimensions mismatch");
+
+        let mut tensor = Tensor::zeros(<(X, W)>::from_dims(&[self.dim(0), rhs.dim(1)]));
+
+        gemm::<T, L, K>(&self.remap(), &rhs.remap(), &mut tensor.remap_mut());
+
+        tensor
+    }
+}
+
+impl<T, X: Dim, Y: Dim, Z: Dim, L: Layout> Slice<T, (X, Y, Z), L> {
+    /// Returns the batched matrix product of two arrays of rank 3.
+    ///
+    /// The first dimension is the batch dimension, where a size of one is broadcast
+    /// to the size of the other array. Zero elements for the result are created using
+    /// `Default::default()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the batch dimensions cannot be broadcast, or if the number of columns
+    /// in the left array is not equal to the number of rows in the right array.
+    pub fn matmul<U: Dim, V: Dim, W: Dim, K: Layout>(
+        &self,
+        rhs: &Slice<T, (U, V, W), K>,
+    ) -> Tensor<T, (X::Merge<U>, Y, W)>
+    where
+        T: Add<Output = T> + Copy + Default + Mul<Output = T>,
+    {
+        let (a_len, b_len) = (self.dim(0), rhs.dim(0));
+
+        assert!(a_len == b_len || a_len == 1 || b_len == 1, "incompatible shapes");
+        assert!(self.dim(2) == rhs.dim(1), "inner dimensions mismatch");
+
+        let len = if a_len == 1 { b_len } else { a_len };
+        let shape = <(X::Merge<U>, Y, W)>::from_dims(&[len, self.dim(1), rhs.dim(2)]);
+
+        let mut tensor = Tensor::zeros(shape);
+
+        for i in 0..len {
+            let a = self.at(if a_len == 1 { 0 } else { i });
+            let b = rhs.at(if b_len == 1 { 0 } else { i });
+
+            gemm::<T, L, K>(&a.remap(), &b.remap(), &mut tensor.at_mut(i).remap_mut());
+        }
+
+        tensor
+    }
+}
+
+impl<T, S: Shape, L: Layout> Slice<T, S, L> {
+    /// Returns the tensor contraction of two arrays over the specified pairs of dimensions.
+    ///
+    /// Each pair `(i, j)` gives a dimension `i` in the left array that is summed over
+    /// together with a dimension `j` in the right array. The resulting array has the
+    /// remaining dimensions of the left array followed by the remaining dimensions of
+    /// the right array. Zero elements for the result are created using `Default::default()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::{tensor, view};
+    ///
+    /// let a = tensor![[1, 2, 3], [4, 5, 6]];
+    /// let b = tensor![[1, 0], [0, 1], [1, 1]];
+    ///
+    /// assert_eq!(a.tensordot(&b, &[(1, 0)]), view![[4, 5], [10, 11]].into_dyn());
+    /// assert_eq!(a.tensordot(&a, &[(0, 0), (1, 1)]), view![91].into_shape([]).into_dyn());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if a dimension is out of bounds or given more than once, or if the sizes
+    /// of the dimensions in a pair are not equal.
+    pub fn tensordot<R: Shape, K: Layout>(
+        &self,
+        rhs: &Slice<T, R, K>,
+        axes: &[(usize, usize)],
+    ) -> Tensor<T, DynRank>
+    where
+        T: Add<Output = T> + Copy + Default + Mul<Output = T>,
+    {
+        let mut a_axes = Vec::with_capacity(axes.len());
+        let mut b_axes = Vec::with_capacity(axes.len());
+
+        for &(i, j) in axes {
+            assert!(i < self.rank() && j < rhs.rank(), "invalid dimension");
+            assert!(!a_axes.contains(&i) && !b_axes.contains(&j), "invalid dimension");
+            assert!(self.dim(i) == rhs.dim(j), "dimensions mismatch");
+
+            a_axes.push(i);
+            b_axes.push(j);
+        }
+
+        let a_free = (0..self.rank()).filter(|i| !a_axes.contains(i)).collect::<Vec<_>>();
+        let b_free = (0..rhs.rank()).filter(|j| !b_axes.contains(j)).collect::<Vec<_>>();
+
+        let m = a_free.iter().map(|&i| self.dim(i)).product::<usize>();
+        let n = b_free.iter().map(|&j| rhs.dim(j)).product::<usize>();
+        let k = a_axes.iter().map(|&i| self.dim(i)).product::<usize>();
+
+        let mut dims = a_free.iter().map(|&i| self.dim(i)).collect::<Vec<_>>();
+
+        dims.extend(b_free.iter().map(|&j| rhs.dim(j)));
+
+        // Permute dimensions so that the left array has the contracted dimensions last,
+        // and the right array has them first.
+        let a_perm = [a_free, a_axes].concat();
+        let b_perm = [b_axes, b_free].concat();
+
+        let (a_tmp, b_tmp);
+
+        let a = self.permute(&a_perm[..]);
+        let b = rhs.permute(&b_perm[..]);
+
+        // Copy to a new array if the dimensions cannot be merged to a matrix.
+        let a = if a.is_contiguous() {
+            a.into_shape([m, k])
+        } else {
+            a_tmp = a.to_tensor();
+            a_tmp.reshape([m, k]).into_mapping()
+        };
+
+        let b = if b.is_contiguous() {
+            b.into_shape([k, n])
+        } else {
+            b_tmp = b.to_tensor();
+            b_tmp.reshape([k, n]).into_mapping()
+        };
+
+        let mut tensor = Tensor::zeros([m, n]);
+
+        gemm(&a, &b, &mut tensor);
+
+        tensor.into_shape(dims)
+    }
+}
+
+// Cache-blocked matrix multiply-add. Blocks from the input matrices are packed into
+// contiguous panels of MR rows and NR columns, and each pair of panels is multiplied
+// with a register-blocked kernel that the compiler can vectorize for any input layout.
+pub(crate) fn gemm<T, L: Layout, K: Layout>(
+    a: &DSlice<T, 2, L>,
+    b: &DSlice<T, 2, K>,
+    c: &mut DSlice<T, 2, Dense>,
+) where
+    T: Add<Output = T> + Copy + Default + Mul<Output = T>,
+{
+    let (m, k, n) = (a.dim(0), a.dim(1), b.dim(1));
+
+    assert!(b.dim(0) == k && c.dim(0) == m && c.dim(1) == n, "shape mismatch");
+
+    let c = &mut c[..];
+
+    let mut a_pack = Vec::new();
+    let mut b_pack = Vec::new();
+
+    for jc in (0..n).step_by(NC) {
+        let nc = NC.min(n - jc);
+
+        for pc in (0..k).step_by(KC) {
+            let kc = KC.min(k - pc);
+
+            pack::<T, _, NR>(&mut b_pack, &b.view(pc..pc + kc, jc..jc + nc).transpose());
+
+            for ic in (0..m).step_by(MC) {
+                let mc = MC.min(m - ic);
+
+                pack::<T, _, MR>(&mut a_pack, &a.view(ic..ic + mc, pc..pc + kc));
+
+                for jr in (0..nc).step_by(NR) {
+                    let b_panel = &b_pack[jr * kc..][..NR * kc];
+
+                    for ir in (0..mc).step_by(MR) {
+                        let a_panel = &a_pack[ir * kc..][..MR * kc];
+                        let acc = kernel(a_panel, b_panel);
+
+                        for i in 0..MR.min(mc - ir) {
+                            let row = &mut c[(ic + ir + i) * n + jc + jr..];
+
+                            for j in 0..NR.min(nc - jr) {
+                                row[j] = row[j] + acc[i][j];
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn kernel<T>(a_panel: &[T], b_panel: &[T]) -> [[T; NR]; MR]
+where
+    T: Add<Output = T> + Copy + Default + Mul<Output = T>,
+{
+    let mut acc = [[T::default(); NR]; MR];
+
+    for (a, b) in a_panel.chunks_exact(MR).zip(b_panel.chunks_exact(NR)) {
+        for i in 0..MR {
+            for j in 0..NR {
+                acc[i][j] = acc[i][j] + a[i] * b[j];
+            }
+        }
+    }
+
+    acc
+}
+
+// Pack the rows of a matrix into panels of N rows, where each panel is stored with
+// the elements in a column next to each other. The last panel is padded with zeros.
+fn pack<T: Copy + Default, L: Layout, const N: usize>(vec: &mut Vec<T>, src: &DSlice<T, 2, L>) {
+    let (m, k) = (src.dim(0), src.dim(1));
+
+    vec.clear();
+    vec.resize(m.div_ceil(N) * N * k, T::default());
+
+    for (i, row) in src.rows().into_iter().enumerate() {
+        let panel = &mut vec[(i / N) * N * k + i % N..];
+
+        for (p, &x) in row.iter().enumerate() {
+            panel[p * N] = x;
+        }
+    }
+}
@@ -0,0 +1,754 @@
+//! Reading and writing arrays in the NumPy `.npy` and `.npz` file formats.
+//!
+//! Arrays are written in row-major order with a header for the format version 1.0,
+//! or version 2.0 if the header is too large. When reading, all versions 1.0 to 3.0
+//! are supported, and arrays stored in column-major order are converted to row-major.
+//! The element types that can be read and written are given by the `Element` trait.
+//!
+//! The `.npz` format is a zip archive of `.npy` files, and is available with
+//! the `npz` feature. Only uncompressed archives are supported.
+//!
+//! With the `memmap` feature, `.npy` files can be memory-mapped using `MmapTensor` and
+//! `MmapTensorMut`. The arrays dereference to array slices, and the data is loaded from
+//! the file when accessed, so that arrays larger than the available memory can be used.
+//!
+//! # Examples
+//!
+//! ```
+//! use mdarray::{DTensor, Tensor, npy, tensor};
+//!
+//! let t = tensor![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+//! let mut buf = Vec::new();
+//!
+//! npy::write(&mut buf, &t).unwrap();
+//!
+//! let u: DTensor<f64, 2> = npy::read(&buf[..]).unwrap();
+//! let v: Tensor<f64> = npy::read(&buf[..]).unwrap();
+//!
+//! assert_eq!(u, t);
+//! assert_eq!(v.dims(), &[2, 3]);
+//! ```
+
+#[cfg(feature = "memmap")]
+use std::fmt::{self, Debug, Formatter};
+#[cfg(feature = "memmap")]
+use std::fs::{File, OpenOptions};
+use std::io::{self, ErrorKind, Read, Write};
+use std::mem;
+#[cfg(feature = "memmap")]
+use std::ops::{Deref, DerefMut};
+#[cfg(feature = "memmap")]
+use std::path::Path;
+
+#[cfg(feature = "memmap")]
+use memmap2::{Mmap, MmapMut};
+#[cfg(feature = "num-complex")]
+use num_complex::Complex;
+
+#[cfg(feature = "npz")]
+use std::io::Seek;
+
+#[cfg(feature = "npz")]
+use zip::write::SimpleFileOptions;
+#[cfg(feature = "npz")]
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::dim::Dyn;
+#[cfg(feature = "memmap")]
+use crate::layout::Dense;
+use crate::layout::Layout;
+#[cfg(feature = "memmap")]
+use crate::mapping::DenseMapping;
+#[cfg(feature = "memmap")]
+use crate::raw_slice::RawSlice;
+#[cfg(feature = "memmap")]
+use crate::shape::IntoShape;
+use crate::shape::{self, DynRank, Shape};
+use crate::slice::Slice;
+use crate::tensor::Tensor;
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+// Size of the buffer used when converting elements to and from bytes. The buffer is
+// extended to hold at least one element.
+const BUF_SIZE: usize = 8192;
+
+/// Element type that can be stored in the `.npy` format.
+///
+/// # Safety
+///
+/// Memory-mapped arrays use the data in place, so the type must have no padding bytes,
+/// and every byte pattern of its size must be a valid value. If not all byte patterns
+/// are valid, `NEEDS_VALIDATION` must be set to true and `from_le_bytes` must return an
+/// error for the invalid byte patterns.
+pub unsafe trait Element: Copy {
+    /// Data type description in NumPy array protocol format with little-endian byte order,
+    /// for example `<f8` for `f64`.
+    const DESCR: &'static str;
+
+    // Size of the components that are byte swapped for big-endian data.
+    #[doc(hidden)]
+    const SWAP_SIZE: usize = mem::size_of::<Self>();
+
+    /// True if not all byte patterns are valid, so that memory-mapped data is checked
+    /// with `from_le_bytes` before use.
+    const NEEDS_VALIDATION: bool = false;
+
+    #[doc(hidden)]
+    fn from_le_bytes(bytes: &[u8]) -> io::Result<Self>;
+
+    #[doc(hidden)]
+    fn to_le_bytes(self, bytes: &mut [u8]);
+}
+
+/// Array in a memory-mapped `.npy` file for reading.
+///
+/// The array dereferences to an array slice, and the file is unmapped when dropped.
+#[cfg(feature = "memmap")]
+pub struct MmapTensor<T, S: Shape = DynRank> {
+    slice: RawSlice<T, S, Dense>,
+    _mmap: Mmap,
+}
+
+/// Array in a memory-mapped `.npy` file for reading and writing.
+///
+/// The array dereferences to a mutable array slice. Changes are written to the file
+/// when flushed, and the file is flushed and unmapped when dropped.
+#[cfg(feature = "memmap")]
+pub struct MmapTensorMut<T, S: Shape = DynRank> {
+    slice: RawSlice<T, S, Dense>,
+    mmap: MmapMut,
+}
+
+/// Reader for arrays in a `.npz` file.
+#[cfg(feature = "npz")]
+pub struct NpzReader<R: Read + Seek> {
+    archive: ZipArchive<R>,
+}
+
+/// Writer for arrays to a `.npz` file.
+#[cfg(feature = "npz")]
+pub struct NpzWriter<W: Write + Seek> {
+    writer: ZipWriter<W>,
+}
+
+struct Header {
+    descr: String,
+    fortran_order: bool,
+    shape: Vec<usize>,
+}
+
+unsafe impl Element for bool {
+    const DESCR: &'static str = "|b1";
+    const NEEDS_VALIDATION: bool = true;
+
+    fn from_le_bytes(bytes: &[u8]) -> io::Result<Self> {
+        match bytes[0] {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(invalid_data("invalid boolean value")),
+        }
+    }
+
+    fn to_le_bytes(self, bytes: &mut [u8]) {
+        bytes[0] = self as u8;
+    }
+}
+
+macro_rules! impl_element {
+    ($type:ty, $descr:literal) => {
+        unsafe impl Element for $type {
+            const DESCR: &'static str = $descr;
+
+            fn from_le_bytes(bytes: &[u8]) -> io::Result<Self> {
+                Ok(<$type>::from_le_bytes(bytes.try_into().expect("invalid length")))
+            }
+
+            fn to_le_bytes(self, bytes: &mut [u8]) {
+                bytes.copy_from_slice(&<$type>::to_le_bytes(self));
+            }
+        }
+    };
+}
+
+impl_element!(i8, "|i1");
+impl_element!(i16, "<i2");
+impl_element!(i32, "<i4");
+impl_element!(i64, "<i8");
+impl_element!(u8, "|u1");
+impl_element!(u16, "<u2");
+impl_element!(u32, "<u4");
+impl_element!(u64, "<u8");
+impl_element!(f32, "<f4");
+impl_element!(f64, "<f8");
+
+#[cfg(feature = "num-complex")]
+macro_rules! impl_complex_element {
+    ($type:ty, $descr:literal) => {
+        unsafe impl Element for Complex<$type> {
+            const DESCR: &'static str = $descr;
+            const SWAP_SIZE: usize = mem::size_of::<$type>();
+
+            fn from_le_bytes(bytes: &[u8]) -> io::Result<Self> {
+                let (re, im) = bytes.split_at(mem::size_of::<$type>());
+
+                Ok(Complex::new(Element::from_le_bytes(re)?, Element::from_le_bytes(im)?))
+            }
+
+            fn to_le_bytes(self, bytes: &mut [u8]) {
+                let (re, im) = bytes.split_at_mut(mem::size_of::<$type>());
+
+                Element::to_le_bytes(self.re, re);
+                Element::to_le_bytes(self.im, im);
+            }
+        }
+    };
+}
+
+#[cfg(feature = "num-complex")]
+impl_complex_element!(f32, "<c8");
+#[cfg(feature = "num-complex")]
+impl_complex_element!(f64, "<c16");
+
+#[cfg(feature = "memmap")]
+impl<T: Element, S: Shape> MmapTensor<T, S> {
+    /// Creates an array from a memory map of a `.npy` file.
+    ///
+    /// The shape type can be `DynRank` to accept any rank, or otherwise the rank and
+    /// any constant-sized dimensions are checked to match the array in the file.
+    ///
+    /// # Errors
+    ///
+    /// If the header is not valid, if the data type or shape is not matching, or if
+    /// the data cannot be used without conversion, then an error is returned.
+    pub fn from_mmap(mmap: Mmap) -> io::Result<Self> {
+        let (offset, shape) = map_header::<T, S>(&mmap)?;
+        let ptr = unsafe { mmap.as_ptr().add(offset) as *mut T };
+
+        Ok(Self {
+            slice: unsafe { RawSlice::new_unchecked(ptr, DenseMapping::new(shape)) },
+            _mmap: mmap,
+        })
+    }
+
+    /// Opens a `.npy` file and maps it into memory for reading.
+    ///
+    /// # Errors
+    ///
+    /// If opening or mapping the file fails, or if the file cannot be used as an array,
+    /// then an error is returned. See `from_mmap` for the requirements on the file.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified by this or other processes while the array is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::npy::{self, MmapTensor};
+    /// use mdarray::{DTensor, tensor};
+    ///
+    /// let path = std::env::temp_dir().join("mdarray_doc_mmap.npy");
+    ///
+    /// npy::write(std::fs::File::create(&path).unwrap(), &tensor![[1.0, 2.0], [3.0, 4.0]]).unwrap();
+    ///
+    /// let t = unsafe { MmapTensor::<f64, (usize, usize)>::open(&path).unwrap() };
+    ///
+    /// assert_eq!(t.view(.., 1), tensor![2.0, 4.0]);
+    /// # drop(t);
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    pub unsafe fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::from_mmap(unsafe { Mmap::map(&File::open(path)?)? })
+    }
+}
+
+#[cfg(feature = "memmap")]
+impl<T: Debug, S: Shape> Debug for MmapTensor<T, S> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+#[cfg(feature = "memmap")]
+impl<T, S: Shape> Deref for MmapTensor<T, S> {
+    type Target = Slice<T, S>;
+
+    fn deref(&self) -> &Self::Target {
+        self.slice.as_slice()
+    }
+}
+
+#[cfg(feature = "memmap")]
+unsafe impl<T: Send, S: Shape> Send for MmapTensor<T, S> {}
+#[cfg(feature = "memmap")]
+unsafe impl<T: Sync, S: Shape> Sync for MmapTensor<T, S> {}
+
+#[cfg(feature = "memmap")]
+impl<T: Element, S: Shape> MmapTensorMut<T, S> {
+    /// Creates a `.npy` file with all elements set to zero, and maps it into memory
+    /// for reading and writing. If the file exists, it is truncated.
+    ///
+    /// # Errors
+    ///
+    /// If the array length exceeds `isize::MAX` bytes, or if creating or mapping
+    /// the file fails, then an error is returned.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified by other processes while the array is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::npy::{self, MmapTensorMut};
+    /// use mdarray::{DTensor, view};
+    ///
+    /// let path = std::env::temp_dir().join("mdarray_doc_mmap_mut.npy");
+    /// let mut t = unsafe { MmapTensorMut::<i32, (usize, usize)>::create(&path, [2, 3]).unwrap() };
+    ///
+    /// t[[1, 2]] = 5;
+    /// drop(t);
+    ///
+    /// let u: DTensor<i32, 2> = npy::read(std::fs::File::open(&path).unwrap()).unwrap();
+    ///
+    /// assert_eq!(u, view![[0, 0, 0], [0, 0, 5]]);
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    pub unsafe fn create<P: AsRef<Path>, I: IntoShape<IntoShape = S>>(
+        path: P,
+        shape: I,
+    ) -> io::Result<Self> {
+        let shape = shape.into_shape();
+        let len = shape.try_len::<T>().map_err(invalid_data)?;
+
+        let mut header = Vec::new();
+
+        write_header::<T, S, _>(&mut header, &shape)?;
+
+        let mut file =
+            OpenOptions::new().read(true).write(true).create(true).truncate(true).open(path)?;
+
+        file.write_all(&header)?;
+        file.set_len((header.len() + len * mem::size_of::<T>()) as u64)?;
+
+        Self::from_mmap(unsafe { MmapMut::map_mut(&file)? })
+    }
+
+    /// Writes outstanding changes to the file.
+    ///
+    /// # Errors
+    ///
+    /// If writing to the file fails, then an error is returned.
+    pub fn flush(&self) -> io::Result<()> {
+        self.mmap.flush()
+    }
+
+    /// Creates an array from a writable memory map of a `.npy` file.
+    ///
+    /// See `MmapTensor::from_mmap` for the array types.
+    ///
+    /// # Errors
+    ///
+    /// If the header is not valid, if the data type or shape is not matching, or if
+    /// the data cannot be used without conversion, then an error is returned.
+    pub fn from_mmap(mut mmap: MmapMut) -> io::Result<Self> {
+        let (offset, shape) = map_header::<T, S>(&mmap)?;
+        let ptr = unsafe { mmap.as_mut_ptr().add(offset) as *mut T };
+
+        Ok(Self { slice: unsafe { RawSlice::new_unchecked(ptr, DenseMapping::new(shape)) }, mmap })
+    }
+
+    /// Opens a `.npy` file and maps it into memory for reading and writing.
+    ///
+    /// # Errors
+    ///
+    /// If opening or mapping the file fails, or if the file cannot be used as an array,
+    /// then an error is returned. See `MmapTensor::from_mmap` for the requirements on
+    /// the file.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified by other processes while the array is used.
+    pub unsafe fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file = OpenOptions::new().read(true).write(true).open(path)?;
+
+        Self::from_mmap(unsafe { MmapMut::map_mut(&file)? })
+    }
+}
+
+#[cfg(feature = "memmap")]
+impl<T: Debug, S: Shape> Debug for MmapTensorMut<T, S> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+#[cfg(feature = "memmap")]
+impl<T, S: Shape> Deref for MmapTensorMut<T, S> {
+    type Target = Slice<T, S>;
+
+    fn deref(&self) -> &Self::Target {
+        self.slice.as_slice()
+    }
+}
+
+#[cfg(feature = "memmap")]
+impl<T, S: Shape> DerefMut for MmapTensorMut<T, S> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.slice.as_mut_slice()
+    }
+}
+
+#[cfg(feature = "memmap")]
+impl<T, S: Shape> Drop for MmapTensorMut<T, S> {
+    fn drop(&mut self) {
+        // Errors are ignored here, and can be checked by calling flush before dropping.
+        _ = self.mmap.flush();
+    }
+}
+
+#[cfg(feature = "memmap")]
+unsafe impl<T: Send, S: Shape> Send for MmapTensorMut<T, S> {}
+#[cfg(feature = "memmap")]
+unsafe impl<T: Sync, S: Shape> Sync for MmapTensorMut<T, S> {}
+
+#[cfg(feature = "npz")]
+impl<R: Read + Seek> NpzReader<R> {
+    /// Creates a new reader for a `.npz` file.
+    ///
+    /// # Errors
+    ///
+    /// If the file is not a valid zip archive, then an error is returned.
+    pub fn new(reader: R) -> io::Result<Self> {
+        Ok(Self { archive: ZipArchive::new(reader)? })
+    }
+
+    /// Returns an iterator over the array names in the file.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.archive.file_names().map(|name| name.strip_suffix(".npy").unwrap_or(name))
+    }
+
+    /// Reads the array with the specified name, see `read` for the array types.
+    ///
+    /// # Errors
+    ///
+    /// If the array is not found, or if reading the array fails, then an error is returned.
+    pub fn read<T: Element, S: Shape>(&mut self, name: &str) -> io::Result<Tensor<T, S>> {
+        let file = self.archive.by_name(&format!("{name}.npy"))?;
+
+        if file.compression() != CompressionMethod::Stored {
+            return Err(io::Error::new(ErrorKind::Unsupported, "compressed array not supported"));
+        }
+
+        read(file)
+    }
+}
+
+#[cfg(feature = "npz")]
+impl<W: Write + Seek> NpzWriter<W> {
+    /// Creates a new writer for a `.npz` file.
+    pub fn new(writer: W) -> Self {
+        Self { writer: ZipWriter::new(writer) }
+    }
+
+    /// Finishes writing the file, and returns the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// If writing the zip archive fails, then an error is returned.
+    pub fn finish(self) -> io::Result<W> {
+        Ok(self.writer.finish()?)
+    }
+
+    /// Writes an array with the specified name to the file.
+    ///
+    /// # Errors
+    ///
+    /// If writing the array fails, then an error is returned.
+    pub fn write<T: Element, S: Shape, L: Layout>(
+        &mut self,
+        name: &str,
+        array: &Slice<T, S, L>,
+    ) -> io::Result<()> {
+        let options = SimpleFileOptions::default()
+            .compression_method(CompressionMethod::Stored)
+            .large_file(array.len().saturating_mul(mem::size_of::<T>()) >= u32::MAX as usize);
+
+        self.writer.start_file(format!("{name}.npy"), options)?;
+
+        write(&mut self.writer, array)
+    }
+}
+
+/// Reads an array from the `.npy` format.
+///
+/// The shape type can be `DynRank` to accept any rank, or otherwise the rank and
+/// any constant-sized dimensions are checked to match the array in the file.
+///
+/// # Errors
+///
+/// If the header is not valid, if the data type or shape is not matching, if the
+/// element type is zero-sized, or if reading fails, then an error is returned.
+pub fn read<T: Element, S: Shape, R: Read>(mut reader: R) -> io::Result<Tensor<T, S>> {
+    let header = read_header(&mut reader)?;
+
+    if !is_matching_descr(&header.descr, T::DESCR) {
+        let msg = format!("data type mismatch, found '{}'", header.descr);
+
+        return Err(invalid_data(msg));
+    }
+
+    let len = shape::try_len::<T>(&header.shape).map_err(invalid_data)?;
+
+    let size = element_size::<T>()?;
+    let swap = header.descr.starts_with('>') && T::SWAP_SIZE > 1;
+
+    let mut vec = Vec::new();
+    let mut buf = vec![0; (BUF_SIZE / size).max(1) * size];
+
+    while vec.len() < len {
+        let count = (len - vec.len()).min(buf.len() / size);
+        let bytes = &mut buf[..count * size];
+
+        reader.read_exact(bytes)?;
+
+        for chunk in bytes.chunks_exact_mut(size) {
+            if swap {
+                chunk.chunks_exact_mut(T::SWAP_SIZE).for_each(<[u8]>::reverse);
+            }
+
+            vec.push(T::from_le_bytes(chunk)?);
+        }
+    }
+
+    let mut dims = header.shape;
+
+    // Arrays in column-major order are read with the dimensions reversed and transposed.
+    if header.fortran_order {
+        dims.reverse();
+    }
+
+    let tensor = Tensor::<T, (Dyn,)>::from(vec).into_shape(DynRank::from_dims(&dims));
+    let tensor = if header.fortran_order { tensor.transpose().to_tensor() } else { tensor };
+
+    tensor.try_into_mapping().map_err(|(_, err)| invalid_data(err))
+}
+
+/// Writes an array to the `.npy` format.
+///
+/// The elements are written in row-major order, independent of the array layout.
+///
+/// # Errors
+///
+/// If the element type is zero-sized or if writing fails, then an error is returned.
+pub fn write<T: Element, S: Shape, L: Layout, W: Write>(
+    mut writer: W,
+    array: &Slice<T, S, L>,
+) -> io::Result<()> {
+    let size = element_size::<T>()?;
+
+    write_header::<T, S, _>(&mut writer, array.shape())?;
+
+    let mut buf = vec![0; (BUF_SIZE / size).max(1) * size];
+    let mut count = 0;
+
+    for &x in array {
+        x.to_le_bytes(&mut buf[count..count + size]);
+        count += size;
+
+        if count == buf.len() {
+            writer.write_all(&buf)?;
+            count = 0;
+        }
+    }
+
+    writer.write_all(&buf[..count])
+}
+
+fn element_size<T>() -> io::Result<usize> {
+    match mem::size_of::<T>() {
+        0 => Err(io::Error::new(ErrorKind::Unsupported, "zero-sized element type")),
+        size => Ok(size),
+    }
+}
+
+fn invalid_data<E: Into<Box<dyn std::error::Error + Send + Sync>>>(error: E) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, error)
+}
+
+fn is_matching_descr(descr: &str, expected: &str) -> bool {
+    let (order, kind) = descr.split_at_checked(1).unwrap_or_default();
+
+    // Single byte types can have any byte order, and otherwise native order is accepted
+    // on little-endian targets.
+    match order {
+        "<" | ">" | "|" => kind == &expected[1..],
+        "=" => cfg!(target_endian = "little") && kind == &expected[1..],
+        _ => false,
+    }
+}
+
+#[cfg(feature = "memmap")]
+fn map_header<T: Element, S: Shape>(bytes: &[u8]) -> io::Result<(usize, S)> {
+    let mut reader = bytes;
+    let header = read_header(&mut reader)?;
+    let offset = bytes.len() - reader.len();
+
+    if !is_matching_descr(&header.descr, T::DESCR) {
+        let msg = format!("data type mismatch, found '{}'", header.descr);
+
+        return Err(invalid_data(msg));
+    }
+
+    // The data is used in place, so it must not need to be transposed or byte swapped.
+    if header.fortran_order {
+        return Err(io::Error::new(ErrorKind::Unsupported, "column-major order not supported"));
+    }
+
+    let swapped = if cfg!(target_endian = "little") { '>' } else { '<' };
+
+    if header.descr.starts_with(swapped) && T::SWAP_SIZE > 1 {
+        return Err(io::Error::new(ErrorKind::Unsupported, "byte order not supported"));
+    }
+
+    let shape = S::try_from_dims(&header.shape).map_err(invalid_data)?;
+    let size = shape.try_len::<T>().map_err(invalid_data)? * element_size::<T>()?;
+
+    let data = reader.get(..size).ok_or(ErrorKind::UnexpectedEof)?;
+
+    if !(data.as_ptr() as *const T).is_aligned() {
+        return Err(invalid_data("data not aligned"));
+    }
+
+    if T::NEEDS_VALIDATION {
+        for chunk in data.chunks_exact(mem::size_of::<T>()) {
+            _ = T::from_le_bytes(chunk)?;
+        }
+    }
+
+    Ok((offset, shape))
+}
+
+fn parse_header(text: &str) -> Option<Header> {
+    let mut text = text.trim().strip_prefix('{')?.strip_suffix('}')?.trim();
+
+    let mut descr = None;
+    let mut fortran_order = None;
+    let mut shape = None;
+
+    while !text.is_empty() {
+        let (key, rest) = parse_str(text)?;
+        let rest = rest.trim_start().strip_prefix(':')?.trim_start();
+
+        text = match key {
+            "descr" => {
+                let (value, rest) = parse_str(rest)?;
+
+                descr = Some(value.to_string());
+                rest
+            }
+            "fortran_order" => {
+                let (value, rest) = if let Some(rest) = rest.strip_prefix("True") {
+                    (true, rest)
+                } else {
+                    (false, rest.strip_prefix("False")?)
+                };
+
+                fortran_order = Some(value);
+                rest
+            }
+            "shape" => {
+                let (value, rest) = rest.strip_prefix('(')?.split_once(')')?;
+                let dims = value.split(',').map(str::trim).filter(|x| !x.is_empty());
+
+                shape = Some(dims.map(|x| x.parse().ok()).collect::<Option<Vec<_>>>()?);
+                rest
+            }
+            _ => return None,
+        };
+
+        text = text.trim_start();
+        text = text.strip_prefix(',').unwrap_or(text).trim_start();
+    }
+
+    Some(Header { descr: descr?, fortran_order: fortran_order?, shape: shape? })
+}
+
+fn parse_str(text: &str) -> Option<(&str, &str)> {
+    let quote = text.chars().next().filter(|&c| c == '\'' || c == '"')?;
+    let (value, rest) = text[1..].split_once(quote)?;
+
+    Some((value, rest))
+}
+
+fn read_header<R: Read>(reader: &mut R) -> io::Result<Header> {
+    let mut prefix = [0; 8];
+
+    reader.read_exact(&mut prefix)?;
+
+    if &prefix[..6] != MAGIC {
+        return Err(invalid_data("invalid magic string"));
+    }
+
+    let header_len = match prefix[6] {
+        1 => {
+            let mut bytes = [0; 2];
+
+            reader.read_exact(&mut bytes)?;
+            u16::from_le_bytes(bytes) as usize
+        }
+        2 | 3 => {
+            let mut bytes = [0; 4];
+
+            reader.read_exact(&mut bytes)?;
+            u32::from_le_bytes(bytes) as usize
+        }
+        major => return Err(invalid_data(format!("unsupported version {major}"))),
+    };
+
+    let mut bytes = vec![0; header_len];
+
+    reader.read_exact(&mut bytes)?;
+
+    // Version 3.0 uses UTF-8 for the header, and earlier versions use latin-1.
+    let text = if prefix[6] == 3 {
+        String::from_utf8(bytes).map_err(invalid_data)?
+    } else {
+        bytes.iter().map(|&x| x as char).collect()
+    };
+
+    parse_header(&text).ok_or_else(|| invalid_data("invalid header"))
+}
+
+fn write_header<T: Element, S: Shape, W: Write>(writer: &mut W, shape: &S) -> io::Result<()> {
+    let dims = shape.with_dims(|dims| match dims {
+        [size] => format!("({size},)"),
+        _ => format!("({})", dims.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", ")),
+    });
+
+    let dict = format!("{{'descr': '{}', 'fortran_order': False, 'shape': {dims}, }}", T::DESCR);
+
+    // Pad the header with spaces and a newline so that the data is aligned to 64 bytes.
+    // The header length is stored in 2 bytes for version 1.0, and 4 bytes for 2.0.
+    let mut version = 1;
+    let mut header_len = (dict.len() + 11).next_multiple_of(64) - 10;
+
+    if header_len > u16::MAX as usize {
+        version = 2;
+        header_len = (dict.len() + 13).next_multiple_of(64) - 12;
+    }
+
+    writer.write_all(MAGIC)?;
+    writer.write_all(&[version, 0])?;
+
+    if version == 1 {
+        writer.write_all(&(header_len as u16).to_le_bytes())?;
+    } else {
+        writer.write_all(&(header_len as u32).to_le_bytes())?;
+    }
+
+    writer.write_all(dict.as_bytes())?;
+    writer.write_all(&b" ".repeat(header_len - dict.len() - 1))?;
+    writer.write_all(b"\n")
+}
@@ -11,7 +11,7 @@ use crate::alloc::Allocator;
 use crate::layout::Dense;
 use crate::mapping::{DenseMapping, Mapping};
 use crate::raw_slice::RawSlice;
-use crate::shape::Shape;
+use crate::shape::{self, Shape};
 use crate::slice::Slice;
 
 #[cfg(not(feature = "nightly"))]
@@ -117,8 +117,7 @@ impl<T, S: Shape, A: Allocator> RawTensor<T, S, A> {
         assert!(new_dims.len() == self.slice.mapping().rank(), "invalid rank");
 
         if !new_dims.is_empty() {
-            let new_len = new_dims.iter().try_fold(1usize, |acc, &x| acc.checked_mul(x));
-            let new_len = new_len.expect("invalid length");
+            let new_len = shape::try_len::<T>(new_dims).expect("invalid length");
 
             unsafe {
                 self.with_mut_parts(|vec, old_mapping| {
@@ -4,7 +4,7 @@ use std::fmt::{self, Formatter};
 use std::marker::PhantomData;
 
 use serde::de::{Error, SeqAccess, Visitor};
-use serde::ser::SerializeSeq;
+use serde::ser::{self, SerializeSeq};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 #[cfg(not(feature = "nightly"))]
@@ -18,6 +18,8 @@ use crate::tensor::Tensor;
 use crate::view::{View, ViewMut};
 use crate::{array, tensor};
 
+const MAX_RESERVE: usize = 4096;
+
 struct TensorVisitor<T, S: Shape> {
     phantom: PhantomData<(T, S)>,
 }
@@ -26,17 +28,23 @@ impl<'a, T: Deserialize<'a>, S: Shape> Visitor<'a> for TensorVisitor<T, S> {
     type Value = Tensor<T, S>;
 
     fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
-        write!(formatter, "an array of rank {}", S::RANK.expect("invalid rank"))
+        match S::RANK {
+            Some(rank) => write!(formatter, "an array of rank {rank}"),
+            None => write!(formatter, "an array of static rank"),
+        }
     }
 
     fn visit_seq<A: SeqAccess<'a>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
-        assert!(S::RANK.is_some_and(|rank| rank > 0), "invalid rank");
+        if S::RANK.is_none_or(|rank| rank == 0) {
+            return Err(A::Error::custom("invalid rank"));
+        }
 
         let mut vec = Vec::new();
         let mut shape = S::default();
         let mut size = 0;
 
-        let size_hint = seq.size_hint().unwrap_or(0);
+        // Limit the capacity reserved from the size hint, since the input is not trusted.
+        let size_hint = seq.size_hint().unwrap_or(0).min(MAX_RESERVE);
 
         if S::RANK == Some(1) {
             vec.reserve(size_hint);
@@ -48,7 +56,9 @@ impl<'a, T: Deserialize<'a>, S: Shape> Visitor<'a> for TensorVisitor<T, S> {
         } else {
             while let Some(value) = seq.next_element::<Tensor<T, S::Tail>>()? {
                 if size == 0 {
-                    vec.reserve(value.len() * size_hint);
+                    vec.reserve(
+                        value.len().checked_mul(size_hint).map_or(0, |x| x.min(MAX_RESERVE)),
+                    );
                     shape.with_mut_dims(|dims| {
                         value.shape().with_dims(|src| dims[1..].copy_from_slice(src));
                     });
@@ -81,6 +91,8 @@ impl<'a, T: Deserialize<'a>, S: Shape> Visitor<'a> for TensorVisitor<T, S> {
             return Err(A::Error::custom(msg));
         }
 
+        _ = shape.try_len::<T>().map_err(A::Error::custom)?;
+
         Ok(Tensor::from(vec).into_shape(shape))
     }
 }
@@ -99,7 +111,9 @@ impl<'a, T: Deserialize<'a>, S: ConstShape> Deserialize<'a> for Array<T, S> {
 
 impl<'a, T: Deserialize<'a>, S: Shape> Deserialize<'a> for Tensor<T, S> {
     fn deserialize<R: Deserializer<'a>>(deserializer: R) -> Result<Self, R::Error> {
-        let rank = S::RANK.expect("dynamic rank not supported");
+        let Some(rank) = S::RANK else {
+            return Err(R::Error::custom("dynamic rank not supported"));
+        };
 
         if rank > 0 {
             let visitor = TensorVisitor { phantom: PhantomData };
@@ -121,7 +135,9 @@ impl<T: Serialize, S: ConstShape> Serialize for Array<T, S> {
 
 impl<T: Serialize, S: Shape, L: Layout> Serialize for Slice<T, S, L> {
     fn serialize<R: Serializer>(&self, serializer: R) -> Result<R::Ok, R::Error> {
-        let rank = S::RANK.expect("dynamic rank not supported");
+        let Some(rank) = S::RANK else {
+            return Err(ser::Error::custom("dynamic rank not supported"));
+        };
 
         if rank == 0 {
             self[[]].serialize(serializer)
@@ -3,10 +3,12 @@ use crate::prelude::*;
 use core::cmp::Ordering;
 use core::fmt::Debug;
 use core::hash::{Hash, Hasher};
+use core::mem;
 use core::slice;
 
 use crate::array::Array;
 use crate::dim::{Const, Dim, Dims, Dyn};
+use crate::error::Error;
 use crate::layout::{Layout, Strided};
 use crate::tensor::Tensor;
 use crate::traits::Owned;
@@ -26,7 +28,7 @@ pub trait Shape: Clone + Debug + Default + Hash + Ord + Send + Sync {
     type Prepend<D: Dim>: Shape;
 
     /// Corresponding shape with dynamically-sized dimensions.
-    type Dyn: Shape;
+    type Dyn: Shape<Dyn = Self::Dyn>;
 
     /// Merge each dimension pair, where constant size is preferred over dynamic.
     /// The result has dynamic rank if at least one of the inputs has dynamic rank.
@@ -96,7 +98,7 @@ pub trait Shape: Clone + Debug + Default + Hash + Ord + Send + Sync {
 
     #[doc(hidden)]
     fn checked_len(&self) -> Option<usize> {
-        self.with_dims(|dims| dims.iter().try_fold(1usize, |acc, &x| acc.checked_mul(x)))
+        self.with_dims(try_len::<()>).ok()
     }
 
     #[doc(hidden)]
@@ -128,32 +130,8 @@ pub trait Shape: Clone + Debug + Default + Hash + Ord + Send + Sync {
     }
 
     #[doc(hidden)]
-    fn reshape<S: Shape>(&self, mut new_shape: S) -> S {
-        let mut inferred = None;
-
-        new_shape.with_mut_dims(|dims| {
-            for i in 0..dims.len() {
-                if dims[i] == usize::MAX {
-                    assert!(inferred.is_none(), "at most one dimension can be inferred");
-
-                    dims[i] = 1;
-                    inferred = Some(i);
-                }
-            }
-        });
-
-        let old_len = self.len();
-        let new_len = new_shape.checked_len().expect("invalid length");
-
-        if let Some(i) = inferred {
-            assert!(old_len % new_len == 0, "length not divisible by the new dimensions");
-
-            new_shape.with_mut_dims(|dims| dims[i] = old_len / new_len);
-        } else {
-            assert!(new_len == old_len, "length must not change");
-        }
-
-        new_shape
+    fn reshape<S: Shape>(&self, new_shape: S) -> S {
+        self.try_reshape(new_shape).unwrap_or_else(|err| panic!("{err}"))
     }
 
     #[doc(hidden)]
@@ -181,6 +159,52 @@ pub trait Shape: Clone + Debug + Default + Hash + Ord + Send + Sync {
 
         shape
     }
+    #[doc(hidden)]
+    fn try_from_dims(dims: &[usize]) -> Result<Self, Error> {
+        check_dims::<Self>(dims)?;
+
+        Ok(Self::from_dims(dims))
+    }
+
+    #[doc(hidden)]
+    fn try_len<T>(&self) -> Result<usize, Error> {
+        self.with_dims(try_len::<T>)
+    }
+
+    #[doc(hidden)]
+    fn try_reshape<S: Shape>(&self, mut new_shape: S) -> Result<S, Error> {
+        let mut inferred = None;
+
+        new_shape.with_mut_dims(|dims| {
+            for i in 0..dims.len() {
+                if dims[i] == usize::MAX {
+                    if inferred.is_some() {
+                        return Err(Error::ShapeMismatch);
+                    }
+
+                    dims[i] = 1;
+                    inferred = Some(i);
+                }
+            }
+
+            Ok(())
+        })?;
+
+        let old_len = self.len();
+        let new_len = new_shape.checked_len().ok_or(Error::Overflow)?;
+
+        if let Some(i) = inferred {
+            if new_len == 0 || old_len % new_len != 0 {
+                return Err(Error::ShapeMismatch);
+            }
+
+            new_shape.with_mut_dims(|dims| dims[i] = old_len / new_len);
+        } else if new_len != old_len {
+            return Err(Error::ShapeMismatch);
+        }
+
+        Ok(new_shape)
+    }
 }
 
 /// Trait for array shape where all dimensions are constant-sized.
@@ -348,7 +372,7 @@ impl Shape for () {
     type Dyn = Self;
     type Merge<S: Shape> = S;
 
-    type Layout<L: Layout> = L;
+    type Layout<L: Layout> = L::Inner;
     type Owned<T> = Array<T, ()>;
 
     type Dims<T: Copy + Debug + Default + Eq + Hash + Send + Sync> = [T; 0];
@@ -602,3 +626,31 @@ impl_into_shape!(3, (Dyn, Dyn, Dyn));
 impl_into_shape!(4, (Dyn, Dyn, Dyn, Dyn));
 impl_into_shape!(5, (Dyn, Dyn, Dyn, Dyn, Dyn));
 impl_into_shape!(6, (Dyn, Dyn, Dyn, Dyn, Dyn, Dyn));
+
+// Returns the number of elements for the dimensions, or `Error::Overflow` if the number
+// of elements or the size in bytes exceeds `isize::MAX`. Offsets and allocation sizes can
+// then be calculated without overflow.
+pub(crate) fn try_len<T>(dims: &[usize]) -> Result<usize, Error> {
+    let len = dims.iter().try_fold(1usize, |acc, &x| acc.checked_mul(x)).ok_or(Error::Overflow)?;
+
+    if len > isize::MAX as usize || len.saturating_mul(mem::size_of::<T>()) > isize::MAX as usize {
+        return Err(Error::Overflow);
+    }
+
+    Ok(len)
+}
+
+// Checks that the dimensions are matching static rank and constant-sized dimensions.
+fn check_dims<S: Shape>(dims: &[usize]) -> Result<(), Error> {
+    match S::RANK {
+        Some(rank) if rank != dims.len() => Err(Error::RankMismatch),
+        Some(0) | None => Ok(()),
+        Some(_) => {
+            if S::Head::SIZE.is_some_and(|size| size != dims[0]) {
+                return Err(Error::ShapeMismatch);
+            }
+
+            check_dims::<S::Tail>(&dims[1..])
+        }
+    }
+}
@@ -2,19 +2,29 @@
 use crate::prelude::*;
 #[cfg(feature = "nightly")]
 use core::alloc::Allocator;
-use core::fmt::{Debug, Formatter, Result};
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Formatter};
 use core::hash::{Hash, Hasher};
+use core::iter::{self, Product, Sum};
 use core::marker::PhantomData;
 use core::mem;
-use core::ops::{Index, IndexMut};
-use core::ptr::NonNull;
+use core::ops::{Div, Index, IndexMut};
+use core::ptr::{self, NonNull};
 
 use crate::array::Array;
+use crate::concat;
 use crate::dim::{Const, Dim, Dyn};
+use crate::error::Error;
+#[cfg(feature = "rayon")]
+use crate::expr::ParExpression;
 use crate::expr::{Apply, Expression, FromExpression, IntoExpression};
-use crate::expr::{AxisExpr, AxisExprMut, Iter, Lanes, LanesMut, Map, Zip};
-use crate::index::{Axis, Cols, DimIndex, Permutation, Resize, Rows, SliceIndex, Split, ViewIndex};
-use crate::layout::{Dense, Layout, Strided};
+use crate::expr::{AxisExpr, AxisExprMut, Chunks, ChunksExact, ChunksExactMut, ChunksMut, Iter};
+use crate::expr::{Lanes, LanesMut};
+use crate::expr::{Map, Windows, Zip};
+use crate::format::{Display, PrintOptions};
+use crate::index::{self, ViewIndex};
+use crate::index::{Axis, Cols, DimIndex, Keep, Permutation, Resize, Rows, SliceIndex, Split};
+use crate::layout::{Dense, DenseColMajor, Layout, Strided};
 use crate::mapping::Mapping;
 use crate::raw_slice::RawSlice;
 use crate::shape::{ConstShape, DynRank, IntoShape, Rank, Shape};
@@ -31,6 +41,84 @@ pub struct Slice<T, S: Shape = DynRank, L: Layout = Dense> {
 pub type DSlice<T, const N: usize, L = Dense> = Slice<T, Rank<N>, L>;
 
 impl<T, S: Shape, L: Layout> Slice<T, S, L> {
+    /// Returns an array with the indices of the largest elements along the specified
+    /// dimension, where the dimension is removed.
+    ///
+    /// If there are several largest elements in a lane, the index of the first is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::{tensor, view};
+    ///
+    /// let t = tensor![[1, 5, 3], [4, 2, 6]];
+    ///
+    /// assert_eq!(t.argmax_axis(0), view![1, 0, 1]);
+    /// assert_eq!(t.argmax_axis(1), view![1, 2]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension is out of bounds, or if the dimension has zero size.
+    pub fn argmax_axis<A: Axis>(&self, axis: A) -> <A::Remove<S> as Shape>::Owned<usize>
+    where
+        T: PartialOrd,
+    {
+        assert!(self.dim(axis.index(self.rank())) > 0, "zero-sized dimension");
+
+        self.lanes(axis).map(|x| argmax(&x)).eval()
+    }
+
+    /// Returns an array with the indices of the smallest elements along the specified
+    /// dimension, where the dimension is removed.
+    ///
+    /// If there are several smallest elements in a lane, the index of the first is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension is out of bounds, or if the dimension has zero size.
+    pub fn argmin_axis<A: Axis>(&self, axis: A) -> <A::Remove<S> as Shape>::Owned<usize>
+    where
+        T: PartialOrd,
+    {
+        assert!(self.dim(axis.index(self.rank())) > 0, "zero-sized dimension");
+
+        self.lanes(axis).map(|x| argmin(&x)).eval()
+    }
+
+    /// Returns an array with the indices that sort each lane along the specified dimension.
+    ///
+    /// The sort is stable, so that equal elements keep their relative order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::{tensor, view};
+    ///
+    /// let t = tensor![[3, 1, 2], [1, 2, 1]];
+    ///
+    /// assert_eq!(t.argsort_axis(1), view![[1, 2, 0], [0, 2, 1]]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension is out of bounds.
+    pub fn argsort_axis<A: Axis>(&self, axis: A) -> Tensor<usize, S>
+    where
+        T: Ord,
+    {
+        let mut tensor = Tensor::from_elem(self.shape().clone(), 0);
+
+        tensor.lanes_mut(axis).zip(self.lanes(axis)).for_each(|(mut x, y)| {
+            let mut perm = (0..y.len()).collect::<Vec<_>>();
+
+            perm.sort_by(|&i, &j| y[i].cmp(&y[j]));
+            x.iter_mut().zip(perm).for_each(|(a, b)| *a = b);
+        });
+
+        tensor
+    }
+
     /// Returns a mutable pointer to the array buffer.
     pub fn as_mut_ptr(&mut self) -> *mut T {
         if mem::size_of::<L::Mapping<S>>() > 0 {
@@ -58,12 +146,53 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
         self.expr_mut().zip(expr).for_each(|(x, y)| y.clone_to(x));
     }
 
+    /// Assigns elements to the positions where the mask is `true`, cloning elements if
+    /// needed.
+    ///
+    /// The elements are assigned in the same order as `select_mask` gathers them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::{tensor, view};
+    ///
+    /// let mut t = tensor![[1, 2], [3, 4]];
+    ///
+    /// t.assign_mask(&view![[true, false], [false, true]], &view![5, 6]);
+    ///
+    /// assert_eq!(t, view![[5, 2], [3, 6]]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mask shape is not matching, or if the number of elements is not
+    /// equal to the number of `true` values in the mask.
+    pub fn assign_mask<K: Layout, I: IntoExpression<Item: IntoCloned<T>>>(
+        &mut self,
+        mask: &Slice<bool, S, K>,
+        values: I,
+    ) {
+        assert!(mask.shape() == self.shape(), "shape mismatch");
+
+        let values = values.into_expr();
+
+        assert!(values.len() == mask.iter().filter(|&&x| x).count(), "length mismatch");
+
+        let mut iter = values.into_iter();
+
+        self.expr_mut().zip(mask).for_each(|(x, &m)| {
+            if m {
+                iter.next().expect("length mismatch").clone_to(x);
+            }
+        });
+    }
+
     /// Returns an array view after indexing the first dimension.
     ///
     /// # Panics
     ///
     /// Panics if the index is out of bounds, or if the rank is not at least 1.
-    pub fn at(&self, index: usize) -> View<T, S::Tail, L> {
+    pub fn at(&self, index: usize) -> View<'_, T, S::Tail, L::Inner> {
         self.axis_at(Const::<0>, index)
     }
 
@@ -72,7 +201,7 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
     /// # Panics
     ///
     /// Panics if the index is out of bounds, or if the rank is not at least 1.
-    pub fn at_mut(&mut self, index: usize) -> ViewMut<T, S::Tail, L> {
+    pub fn at_mut(&mut self, index: usize) -> ViewMut<'_, T, S::Tail, L::Inner> {
         self.axis_at_mut(Const::<0>, index)
     }
 
@@ -85,7 +214,11 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
     /// # Panics
     ///
     /// Panics if the dimension or the index is out of bounds.
-    pub fn axis_at<A: Axis>(&self, axis: A, index: usize) -> View<T, A::Remove<S>, Split<A, S, L>> {
+    pub fn axis_at<A: Axis>(
+        &self,
+        axis: A,
+        index: usize,
+    ) -> View<'_, T, A::Remove<S>, Split<A, S, L>> {
         unsafe { View::axis_at(self.as_ptr(), self.mapping(), axis, index) }
     }
 
@@ -102,7 +235,7 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
         &mut self,
         axis: A,
         index: usize,
-    ) -> ViewMut<T, A::Remove<S>, Split<A, S, L>> {
+    ) -> ViewMut<'_, T, A::Remove<S>, Split<A, S, L>> {
         unsafe { ViewMut::axis_at(self.as_mut_ptr(), self.mapping(), axis, index) }
     }
 
@@ -115,7 +248,7 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
     /// # Panics
     ///
     /// Panics if the dimension is out of bounds.
-    pub fn axis_expr<A: Axis>(&self, axis: A) -> AxisExpr<T, S, L, A> {
+    pub fn axis_expr<A: Axis>(&self, axis: A) -> AxisExpr<'_, T, S, L, A> {
         AxisExpr::new(self, axis)
     }
 
@@ -128,16 +261,119 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
     /// # Panics
     ///
     /// Panics if the dimension is out of bounds.
-    pub fn axis_expr_mut<A: Axis>(&mut self, axis: A) -> AxisExprMut<T, S, L, A> {
+    pub fn axis_expr_mut<A: Axis>(&mut self, axis: A) -> AxisExprMut<'_, T, S, L, A> {
         AxisExprMut::new(self, axis)
     }
 
+    /// Returns an array view that is broadcast to the specified shape.
+    ///
+    /// Dimensions are matched from the end. Added outer dimensions and dimensions of
+    /// size one are repeated by having zero stride, so that no elements are copied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::{tensor, view};
+    ///
+    /// let t = tensor![1, 2, 3];
+    ///
+    /// assert_eq!(t.broadcast_to([2, 3]), view![[1, 2, 3], [1, 2, 3]]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array slice cannot be broadcast to the shape.
+    pub fn broadcast_to<I: IntoShape>(&self, shape: I) -> View<'_, T, I::IntoShape, Strided> {
+        self.expr().into_broadcast(shape)
+    }
+
+    /// Returns an expression that gives non-overlapping array views of the specified size,
+    /// where the outer shape is the number of chunks in each dimension.
+    ///
+    /// If the size does not divide the array shape, the array views at the end of each
+    /// dimension contain the remaining elements and are smaller.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::expr::Expression;
+    /// use mdarray::{tensor, view};
+    ///
+    /// let t = tensor![[1, 2, 3, 4, 5], [6, 7, 8, 9, 10]];
+    /// let c = t.chunks([2, 2]).eval();
+    ///
+    /// assert_eq!(c.shape(), &(1, 3));
+    /// assert_eq!(c[[0, 2]], view![[5], [10]]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rank is not matching, or if the chunk size is zero in some dimension.
+    pub fn chunks<I: IntoShape>(&self, size: I) -> Chunks<'_, T, S, L, I::IntoShape> {
+        Chunks::new(self, size.into_shape())
+    }
+
+    /// Returns an expression that gives non-overlapping array views of the specified size,
+    /// where the outer shape is the number of chunks in each dimension.
+    ///
+    /// If the size does not divide the array shape, the remaining elements at the end of
+    /// each dimension are not included in any chunk.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::expr::Expression;
+    /// use mdarray::{tensor, view};
+    ///
+    /// let t = tensor![[1, 2, 3, 4, 5], [6, 7, 8, 9, 10]];
+    /// let c = t.chunks_exact([2, 2]).eval();
+    ///
+    /// assert_eq!(c.shape(), &(1, 2));
+    /// assert_eq!(c[[0, 1]], view![[3, 4], [8, 9]]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rank is not matching, or if the chunk size is zero in some dimension.
+    pub fn chunks_exact<I: IntoShape>(&self, size: I) -> ChunksExact<'_, T, S, L, I::IntoShape> {
+        ChunksExact::new(self, size.into_shape())
+    }
+
+    /// Returns a mutable expression that gives non-overlapping array views of the specified
+    /// size, where the outer shape is the number of chunks in each dimension.
+    ///
+    /// If the size does not divide the array shape, the array views at the end of each
+    /// dimension contain the remaining elements and are smaller.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rank is not matching, or if the chunk size is zero in some dimension.
+    pub fn chunks_mut<I: IntoShape>(&mut self, size: I) -> ChunksMut<'_, T, S, L, I::IntoShape> {
+        ChunksMut::new(self, size.into_shape())
+    }
+
+    /// Returns a mutable expression that gives non-overlapping array views of the specified
+    /// size, where the outer shape is the number of chunks in each dimension.
+    ///
+    /// If the size does not divide the array shape, the remaining elements at the end of
+    /// each dimension are not included in any chunk.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rank is not matching, or if the chunk size is zero in some dimension.
+    pub fn chunks_exact_mut<I: IntoShape>(
+        &mut self,
+        size: I,
+    ) -> ChunksExactMut<'_, T, S, L, I::IntoShape> {
+        ChunksExactMut::new(self, size.into_shape())
+    }
+
     /// Returns an array view for the specified column.
     ///
     /// # Panics
     ///
     /// Panics if the rank is not equal to 2, or if the index is out of bounds.
-    pub fn col(&self, index: usize) -> View<T, (S::Head,), Strided> {
+    pub fn col(&self, index: usize) -> View<'_, T, (S::Head,), Strided> {
         let shape = self.shape().with_dims(<(_, <S::Tail as Shape>::Head)>::from_dims);
 
         self.reshape(shape).into_view(.., index)
@@ -148,7 +384,7 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
     /// # Panics
     ///
     /// Panics if the rank is not equal to 2, or if the index is out of bounds.
-    pub fn col_mut(&mut self, index: usize) -> ViewMut<T, (S::Head,), Strided> {
+    pub fn col_mut(&mut self, index: usize) -> ViewMut<'_, T, (S::Head,), Strided> {
         let shape = self.shape().with_dims(<(_, <S::Tail as Shape>::Head)>::from_dims);
 
         self.reshape_mut(shape).into_view(.., index)
@@ -159,7 +395,7 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
     /// # Panics
     ///
     /// Panics if the rank is not at least 2.
-    pub fn cols(&self) -> Lanes<T, S, L, Cols> {
+    pub fn cols(&self) -> Lanes<'_, T, S, L, Cols> {
         self.lanes(Cols)
     }
 
@@ -168,7 +404,7 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
     /// # Panics
     ///
     /// Panics if the rank is not at least 2.
-    pub fn cols_mut(&mut self) -> LanesMut<T, S, L, Cols> {
+    pub fn cols_mut(&mut self) -> LanesMut<'_, T, S, L, Cols> {
         self.lanes_mut(Cols)
     }
 
@@ -187,7 +423,7 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
     ///
     /// Panics if the rank is not equal to 2, or if the absolute index is larger
     /// than the number of columns or rows.
-    pub fn diag(&self, index: isize) -> View<T, (Dyn,), Strided> {
+    pub fn diag(&self, index: isize) -> View<'_, T, (Dyn,), Strided> {
         let shape = self.shape().with_dims(<(S::Head, <S::Tail as Shape>::Head)>::from_dims);
 
         self.reshape(shape).into_diag(index)
@@ -200,7 +436,7 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
     ///
     /// Panics if the rank is not equal to 2, or if the absolute index is larger
     /// than the number of columns or rows.
-    pub fn diag_mut(&mut self, index: isize) -> ViewMut<T, (Dyn,), Strided> {
+    pub fn diag_mut(&mut self, index: isize) -> ViewMut<'_, T, (Dyn,), Strided> {
         let shape = self.shape().with_dims(<(S::Head, <S::Tail as Shape>::Head)>::from_dims);
 
         self.reshape_mut(shape).into_diag(index)
@@ -215,13 +451,31 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
         self.mapping().dim(index)
     }
 
+    /// Returns an object that implements `Display` for printing the array slice with
+    /// the given options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::format::PrintOptions;
+    /// use mdarray::tensor;
+    ///
+    /// let t = tensor![[1.0, 2.5, 3.0], [4.0, 5.0, 6.0]];
+    /// let options = PrintOptions { threshold: 4, edge_items: 1, ..Default::default() };
+    ///
+    /// assert_eq!(format!("{:.1}", t.display(options)), "[[1.0 ... 3.0]\n [4.0 ... 6.0]]");
+    /// ```
+    pub fn display(&self, options: PrintOptions) -> Display<'_, T, S, L> {
+        Display::new(self, options)
+    }
+
     /// Returns an expression over the array slice.
-    pub fn expr(&self) -> View<T, S, L> {
+    pub fn expr(&self) -> View<'_, T, S, L> {
         unsafe { View::new_unchecked(self.as_ptr(), self.mapping().clone()) }
     }
 
     /// Returns a mutable expression over the array slice.
-    pub fn expr_mut(&mut self) -> ViewMut<T, S, L> {
+    pub fn expr_mut(&mut self) -> ViewMut<'_, T, S, L> {
         unsafe { ViewMut::new_unchecked(self.as_mut_ptr(), self.mapping().clone()) }
     }
 
@@ -243,7 +497,7 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
     /// # Panics
     ///
     /// Panics if the array layout is not uniformly strided.
-    pub fn flatten(&self) -> View<T, (Dyn,), L> {
+    pub fn flatten(&self) -> View<'_, T, (Dyn,), L> {
         self.reshape([self.len()])
     }
 
@@ -252,10 +506,44 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
     /// # Panics
     ///
     /// Panics if the array layout is not uniformly strided.
-    pub fn flatten_mut(&mut self) -> ViewMut<T, (Dyn,), L> {
+    pub fn flatten_mut(&mut self) -> ViewMut<'_, T, (Dyn,), L> {
         self.reshape_mut([self.len()])
     }
 
+    /// Returns an array view with the order of elements reversed along the specified
+    /// dimension.
+    ///
+    /// The resulting array view has a negative stride for the dimension, and no elements
+    /// are copied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::{tensor, view};
+    ///
+    /// let t = tensor![[1, 2, 3], [4, 5, 6]];
+    ///
+    /// assert_eq!(t.flip(0), view![[4, 5, 6], [1, 2, 3]]);
+    /// assert_eq!(t.flip(1), view![[3, 2, 1], [6, 5, 4]]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension is out of bounds.
+    pub fn flip<A: Axis>(&self, axis: A) -> View<'_, T, S, Strided> {
+        unsafe { View::<T, S, L>::flip(self.as_ptr(), self.mapping(), axis) }
+    }
+
+    /// Returns a mutable array view with the order of elements reversed along the
+    /// specified dimension.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension is out of bounds.
+    pub fn flip_mut<A: Axis>(&mut self, axis: A) -> ViewMut<'_, T, S, Strided> {
+        unsafe { ViewMut::<T, S, L>::flip(self.as_mut_ptr(), self.mapping(), axis) }
+    }
+
     /// Returns a reference to an element or a subslice, without doing bounds checking.
     ///
     /// # Safety
@@ -304,7 +592,7 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
     /// # Panics
     ///
     /// Panics if the dimension is out of bounds.
-    pub fn lanes<A: Axis>(&self, axis: A) -> Lanes<T, S, L, A> {
+    pub fn lanes<A: Axis>(&self, axis: A) -> Lanes<'_, T, S, L, A> {
         Lanes::new(self, axis)
     }
 
@@ -318,7 +606,7 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
     /// # Panics
     ///
     /// Panics if the dimension is out of bounds.
-    pub fn lanes_mut<A: Axis>(&mut self, axis: A) -> LanesMut<T, S, L, A> {
+    pub fn lanes_mut<A: Axis>(&mut self, axis: A) -> LanesMut<'_, T, S, L, A> {
         LanesMut::new(self, axis)
     }
 
@@ -336,6 +624,120 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
         }
     }
 
+    /// Returns an array with the largest elements along the specified dimension,
+    /// where the dimension is removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::{tensor, view};
+    ///
+    /// let t = tensor![[1, 5, 3], [4, 2, 6]];
+    ///
+    /// assert_eq!(t.max_axis(0), view![4, 5, 6]);
+    /// assert_eq!(t.max_axis(1), view![5, 6]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension is out of bounds, or if the dimension has zero size.
+    pub fn max_axis<A: Axis>(&self, axis: A) -> <A::Remove<S> as Shape>::Owned<T>
+    where
+        T: Clone + PartialOrd,
+    {
+        assert!(self.dim(axis.index(self.rank())) > 0, "zero-sized dimension");
+
+        self.lanes(axis).map(|x| max(&x)).eval()
+    }
+
+    /// Returns an array with the largest elements along the specified dimension,
+    /// where the dimension is kept with size one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension is out of bounds, or if the dimension has zero size.
+    pub fn max_axis_keepdims<A: Axis>(&self, axis: A) -> Tensor<T, Resize<A, S>>
+    where
+        T: Clone + PartialOrd,
+    {
+        assert!(self.dim(axis.index(self.rank())) > 0, "zero-sized dimension");
+
+        reduce_keepdims(self, axis, |x| max(&x))
+    }
+
+    /// Returns an array with the mean of the elements along the specified dimension,
+    /// where the dimension is removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::{tensor, view};
+    ///
+    /// let t = tensor![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+    ///
+    /// assert_eq!(t.mean_axis(0), view![2.5, 3.5, 4.5]);
+    /// assert_eq!(t.mean_axis(1), view![2.0, 5.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension is out of bounds. If the dimension has zero size, the
+    /// division panics for integer types, while floating-point types give NaN.
+    pub fn mean_axis<A: Axis>(&self, axis: A) -> <A::Remove<S> as Shape>::Owned<T>
+    where
+        T: Clone + Div<Output = T> + Product + Sum,
+    {
+        let count = count::<T>(self.dim(axis.index(self.rank())));
+
+        self.lanes(axis).map(|x| sum(&x) / count.clone()).eval()
+    }
+
+    /// Returns an array with the mean of the elements along the specified dimension,
+    /// where the dimension is kept with size one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension is out of bounds. If the dimension has zero size, the
+    /// division panics for integer types, while floating-point types give NaN.
+    pub fn mean_axis_keepdims<A: Axis>(&self, axis: A) -> Tensor<T, Resize<A, S>>
+    where
+        T: Clone + Div<Output = T> + Product + Sum,
+    {
+        let count = count::<T>(self.dim(axis.index(self.rank())));
+
+        reduce_keepdims(self, axis, |x| sum(&x) / count.clone())
+    }
+
+    /// Returns an array with the smallest elements along the specified dimension,
+    /// where the dimension is removed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension is out of bounds, or if the dimension has zero size.
+    pub fn min_axis<A: Axis>(&self, axis: A) -> <A::Remove<S> as Shape>::Owned<T>
+    where
+        T: Clone + PartialOrd,
+    {
+        assert!(self.dim(axis.index(self.rank())) > 0, "zero-sized dimension");
+
+        self.lanes(axis).map(|x| min(&x)).eval()
+    }
+
+    /// Returns an array with the smallest elements along the specified dimension,
+    /// where the dimension is kept with size one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension is out of bounds, or if the dimension has zero size.
+    pub fn min_axis_keepdims<A: Axis>(&self, axis: A) -> Tensor<T, Resize<A, S>>
+    where
+        T: Clone + PartialOrd,
+    {
+        assert!(self.dim(axis.index(self.rank())) > 0, "zero-sized dimension");
+
+        reduce_keepdims(self, axis, |x| min(&x))
+    }
+
     /// Returns an expression that gives array views iterating over the first dimension.
     ///
     /// Iterating over the first dimension results in array views with the same layout
@@ -344,7 +746,7 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
     /// # Panics
     ///
     /// Panics if the rank is not at least 1.
-    pub fn outer_expr(&self) -> AxisExpr<T, S, L, Const<0>> {
+    pub fn outer_expr(&self) -> AxisExpr<'_, T, S, L, Const<0>> {
         self.axis_expr(Const::<0>)
     }
 
@@ -356,10 +758,25 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
     /// # Panics
     ///
     /// Panics if the rank is not at least 1.
-    pub fn outer_expr_mut(&mut self) -> AxisExprMut<T, S, L, Const<0>> {
+    pub fn outer_expr_mut(&mut self) -> AxisExprMut<'_, T, S, L, Const<0>> {
         self.axis_expr_mut(Const::<0>)
     }
 
+    /// Assigns an expression to the array slice in parallel with broadcasting, cloning
+    /// elements if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the expression cannot be broadcast to the shape of the array slice.
+    #[cfg(feature = "rayon")]
+    pub fn par_assign<I: IntoExpression<Item: IntoCloned<T> + Send>>(&mut self, expr: I)
+    where
+        T: Send,
+        I::IntoExpr: ParExpression,
+    {
+        self.expr_mut().zip(expr).par_for_each(|(x, y)| y.clone_to(x));
+    }
+
     /// Returns an array view with the dimensions permuted.
     ///
     /// If the permutation is an identity permutation and known at compile time, the
@@ -373,8 +790,12 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
     pub fn permute<I: IntoShape<IntoShape: Permutation>>(
         &self,
         perm: I,
-    ) -> View<T, <I::IntoShape as Permutation>::Shape<S>, <I::IntoShape as Permutation>::Layout<L>>
-    {
+    ) -> View<
+        '_,
+        T,
+        <I::IntoShape as Permutation>::Shape<S>,
+        <I::IntoShape as Permutation>::Layout<L>,
+    > {
         let mapping = perm.into_dims(|dims| Mapping::permute(self.mapping(), dims));
 
         unsafe { View::new_unchecked(self.as_ptr(), mapping) }
@@ -393,13 +814,91 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
     pub fn permute_mut<I: IntoShape<IntoShape: Permutation>>(
         &mut self,
         perm: I,
-    ) -> ViewMut<T, <I::IntoShape as Permutation>::Shape<S>, <I::IntoShape as Permutation>::Layout<L>>
-    {
+    ) -> ViewMut<
+        '_,
+        T,
+        <I::IntoShape as Permutation>::Shape<S>,
+        <I::IntoShape as Permutation>::Layout<L>,
+    > {
         let mapping = perm.into_dims(|dims| Mapping::permute(self.mapping(), dims));
 
         unsafe { ViewMut::new_unchecked(self.as_mut_ptr(), mapping) }
     }
 
+    /// Returns an array with the product of the elements along the specified dimension,
+    /// where the dimension is removed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension is out of bounds.
+    pub fn prod_axis<A: Axis>(&self, axis: A) -> <A::Remove<S> as Shape>::Owned<T>
+    where
+        T: Clone + Product,
+    {
+        self.lanes(axis).map(|x| prod(&x)).eval()
+    }
+
+    /// Returns an array with the product of the elements along the specified dimension,
+    /// where the dimension is kept with size one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension is out of bounds.
+    pub fn prod_axis_keepdims<A: Axis>(&self, axis: A) -> Tensor<T, Resize<A, S>>
+    where
+        T: Clone + Product,
+    {
+        reduce_keepdims(self, axis, |x| prod(&x))
+    }
+
+    /// Assigns subarrays along the specified dimension at the given indices, cloning
+    /// the elements.
+    ///
+    /// The subarray at `indices[i]` is assigned from the subarray at index `i` of the
+    /// values. If an index is repeated, the last assignment takes effect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::{tensor, view};
+    ///
+    /// let mut t = tensor![[1, 2], [3, 4], [5, 6]];
+    ///
+    /// t.put(0, &[2, 0], &view![[7, 8], [9, 0]]);
+    ///
+    /// assert_eq!(t, view![[9, 0], [3, 4], [7, 8]]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension or an index is out of bounds, or if the shape of the
+    /// values is not matching except in the specified dimension, which must have
+    /// the same size as the number of indices.
+    pub fn put<A: Axis, R: Shape, K: Layout>(
+        &mut self,
+        axis: A,
+        indices: &[usize],
+        values: &Slice<T, R, K>,
+    ) where
+        T: Clone,
+    {
+        let index = axis.index(self.rank());
+
+        assert!(values.rank() == self.rank(), "invalid rank");
+
+        for i in 0..self.rank() {
+            let size = if i == index { indices.len() } else { self.dim(i) };
+
+            assert!(values.dim(i) == size, "shape mismatch");
+        }
+
+        assert!(indices.iter().all(|&i| i < self.dim(index)), "index out of bounds");
+
+        for (i, &j) in indices.iter().enumerate() {
+            self.axis_at_mut(axis, j).assign(values.axis_at(axis, i));
+        }
+    }
+
     /// Returns the array rank, i.e. the number of dimensions.
     pub fn rank(&self) -> usize {
         self.mapping().rank()
@@ -410,7 +909,7 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
     /// # Panics
     ///
     /// Panics if the memory layout is not compatible with the new array layout.
-    pub fn remap<R: Shape, K: Layout>(&self) -> View<T, R, K> {
+    pub fn remap<R: Shape, K: Layout>(&self) -> View<'_, T, R, K> {
         let mapping = Mapping::remap(self.mapping());
 
         unsafe { View::new_unchecked(self.as_ptr(), mapping) }
@@ -421,7 +920,7 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
     /// # Panics
     ///
     /// Panics if the memory layout is not compatible with the new array layout.
-    pub fn remap_mut<R: Shape, K: Layout>(&mut self) -> ViewMut<T, R, K> {
+    pub fn remap_mut<R: Shape, K: Layout>(&mut self) -> ViewMut<'_, T, R, K> {
         let mapping = Mapping::remap(self.mapping());
 
         unsafe { ViewMut::new_unchecked(self.as_mut_ptr(), mapping) }
@@ -431,7 +930,7 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
     ///
     /// This method is deprecated, use `transpose` instead.
     #[deprecated]
-    pub fn reorder(&self) -> View<T, S::Reverse, <S::Tail as Shape>::Layout<L>> {
+    pub fn reorder(&self) -> View<'_, T, S::Reverse, <S::Tail as Shape>::Layout<L>> {
         let mapping = Mapping::transpose(self.mapping());
 
         unsafe { View::new_unchecked(self.as_ptr(), mapping) }
@@ -441,7 +940,7 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
     ///
     /// This method is deprecated, use `transpose_mut` instead.
     #[deprecated]
-    pub fn reorder_mut(&mut self) -> ViewMut<T, S::Reverse, <S::Tail as Shape>::Layout<L>> {
+    pub fn reorder_mut(&mut self) -> ViewMut<'_, T, S::Reverse, <S::Tail as Shape>::Layout<L>> {
         let mapping = Mapping::transpose(self.mapping());
 
         unsafe { ViewMut::new_unchecked(self.as_mut_ptr(), mapping) }
@@ -465,7 +964,7 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
     /// # Panics
     ///
     /// Panics if the array length is changed, or if the memory layout is not compatible.
-    pub fn reshape<I: IntoShape>(&self, shape: I) -> View<T, I::IntoShape, L> {
+    pub fn reshape<I: IntoShape>(&self, shape: I) -> View<'_, T, I::IntoShape, L> {
         let mapping = self.mapping().reshape(shape.into_shape());
 
         unsafe { View::new_unchecked(self.as_ptr(), mapping) }
@@ -481,18 +980,52 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
     /// # Panics
     ///
     /// Panics if the array length is changed, or if the memory layout is not compatible.
-    pub fn reshape_mut<I: IntoShape>(&mut self, shape: I) -> ViewMut<T, I::IntoShape, L> {
+    pub fn reshape_mut<I: IntoShape>(&mut self, shape: I) -> ViewMut<'_, T, I::IntoShape, L> {
         let mapping = self.mapping().reshape(shape.into_shape());
 
         unsafe { ViewMut::new_unchecked(self.as_mut_ptr(), mapping) }
     }
 
+    /// Returns an array view rotated by 90 degrees `k` times in the plane given by two
+    /// dimensions, where the rotation is from the first towards the second dimension.
+    ///
+    /// The rotation is done by reversing and swapping the dimensions, and no elements
+    /// are copied. A negative `k` rotates in the opposite direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::{tensor, view};
+    ///
+    /// let t = tensor![[1, 2, 3], [4, 5, 6]];
+    ///
+    /// assert_eq!(t.rot90(1, [0, 1]), view![[3, 6], [2, 5], [1, 4]]);
+    /// assert_eq!(t.rot90(-1, [0, 1]), view![[4, 1], [5, 2], [6, 3]]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if a dimension is out of bounds, or if the dimensions are equal.
+    pub fn rot90(&self, k: isize, axes: [usize; 2]) -> View<'_, T, S::Dyn, Strided> {
+        unsafe { View::<T, S, L>::rot90(self.as_ptr(), self.mapping(), k, axes) }
+    }
+
+    /// Returns a mutable array view rotated by 90 degrees `k` times in the plane given
+    /// by two dimensions, where the rotation is from the first towards the second dimension.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a dimension is out of bounds, or if the dimensions are equal.
+    pub fn rot90_mut(&mut self, k: isize, axes: [usize; 2]) -> ViewMut<'_, T, S::Dyn, Strided> {
+        unsafe { ViewMut::<T, S, L>::rot90(self.as_mut_ptr(), self.mapping(), k, axes) }
+    }
+
     /// Returns an array view for the specified row.
     ///
     /// # Panics
     ///
     /// Panics if the rank is not equal to 2, or if the index is out of bounds.
-    pub fn row(&self, index: usize) -> View<T, (<S::Tail as Shape>::Head,), L> {
+    pub fn row(&self, index: usize) -> View<'_, T, (<S::Tail as Shape>::Head,), L::Inner> {
         let shape = self.shape().with_dims(<(S::Head, _)>::from_dims);
 
         self.reshape(shape).into_view(index, ..)
@@ -503,7 +1036,10 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
     /// # Panics
     ///
     /// Panics if the rank is not equal to 2, or if the index is out of bounds.
-    pub fn row_mut(&mut self, index: usize) -> ViewMut<T, (<S::Tail as Shape>::Head,), L> {
+    pub fn row_mut(
+        &mut self,
+        index: usize,
+    ) -> ViewMut<'_, T, (<S::Tail as Shape>::Head,), L::Inner> {
         let shape = self.shape().with_dims(<(S::Head, _)>::from_dims);
 
         self.reshape_mut(shape).into_view(index, ..)
@@ -514,7 +1050,7 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
     /// # Panics
     ///
     /// Panics if the rank is not at least 1.
-    pub fn rows(&self) -> Lanes<T, S, L, Rows> {
+    pub fn rows(&self) -> Lanes<'_, T, S, L, Rows> {
         self.lanes(Rows)
     }
 
@@ -523,15 +1059,157 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
     /// # Panics
     ///
     /// Panics if the rank is not at least 1.
-    pub fn rows_mut(&mut self) -> LanesMut<T, S, L, Rows> {
+    pub fn rows_mut(&mut self) -> LanesMut<'_, T, S, L, Rows> {
         self.lanes_mut(Rows)
     }
 
+    /// Returns a one-dimensional array with the elements where the mask is `true`,
+    /// cloning the elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::{tensor, view};
+    ///
+    /// let t = tensor![[1, 2], [3, 4]];
+    ///
+    /// assert_eq!(t.select_mask(&view![[true, false], [false, true]]), view![1, 4]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mask shape is not matching.
+    pub fn select_mask<K: Layout>(&self, mask: &Slice<bool, S, K>) -> Tensor<T, (Dyn,)>
+    where
+        T: Clone,
+    {
+        assert!(mask.shape() == self.shape(), "shape mismatch");
+
+        let mut vec = Vec::new();
+
+        self.expr().zip(mask).for_each(|(x, &m)| {
+            if m {
+                vec.push(x.clone());
+            }
+        });
+
+        Tensor::from(vec)
+    }
+
+    /// Reorders each lane along the specified dimension, so that the element at the
+    /// given index is at its sorted position.
+    ///
+    /// All elements before the index are less than or equal to the element at the
+    /// index, and all elements after are greater than or equal. The order within the
+    /// two partitions is unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::tensor;
+    ///
+    /// let mut t = tensor![[5, 1, 4, 2, 3]];
+    ///
+    /// t.select_nth_axis(1, 3);
+    ///
+    /// assert_eq!(t[[0, 3]], 4);
+    /// assert_eq!(t[[0, 4]], 5);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension is out of bounds, or if the index is not less than the
+    /// size of the dimension.
+    pub fn select_nth_axis<A: Axis>(&mut self, axis: A, index: usize)
+    where
+        T: Ord,
+    {
+        let size = self.dim(axis.index(self.rank()));
+
+        if index >= size {
+            index::panic_bounds_check(index, size);
+        }
+
+        self.lanes_mut(axis).for_each(|mut lane| {
+            if lane.is_contiguous() {
+                _ = lane.remap_mut::<(Dyn,), Dense>()[..].select_nth_unstable(index);
+            } else {
+                let mut perm = (0..lane.len()).collect::<Vec<_>>();
+
+                _ = perm.select_nth_unstable_by(index, |&i, &j| lane[i].cmp(&lane[j]));
+                permute_lane(&mut lane, &perm);
+            }
+        });
+    }
+
     /// Returns the array shape.
     pub fn shape(&self) -> &S {
         self.mapping().shape()
     }
 
+    /// Sorts each lane along the specified dimension.
+    ///
+    /// The sort is stable, so that equal elements keep their relative order. Contiguous
+    /// lanes are sorted in place, and otherwise the elements are swapped into their sorted
+    /// positions without cloning.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::{tensor, view};
+    ///
+    /// let mut t = tensor![[3, 1, 2], [1, 2, 0]];
+    ///
+    /// t.sort_axis(0);
+    ///
+    /// assert_eq!(t, view![[1, 1, 0], [3, 2, 2]]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension is out of bounds.
+    pub fn sort_axis<A: Axis>(&mut self, axis: A)
+    where
+        T: Ord,
+    {
+        self.sort_axis_by(axis, T::cmp);
+    }
+
+    /// Sorts each lane along the specified dimension with a comparison function.
+    ///
+    /// The sort is stable, so that equal elements keep their relative order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::{tensor, view};
+    ///
+    /// let mut t = tensor![[0.5, -1.0, 2.0], [1.5, 0.0, -0.5f64]];
+    ///
+    /// t.sort_axis_by(1, |a, b| b.total_cmp(a));
+    ///
+    /// assert_eq!(t, view![[2.0, 0.5, -1.0], [1.5, 0.0, -0.5]]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension is out of bounds.
+    pub fn sort_axis_by<A: Axis, F>(&mut self, axis: A, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.lanes_mut(axis).for_each(|mut lane| {
+            if lane.is_contiguous() {
+                lane.remap_mut::<(Dyn,), Dense>()[..].sort_by(&mut compare);
+            } else {
+                let mut perm = (0..lane.len()).collect::<Vec<_>>();
+
+                perm.sort_by(|&i, &j| compare(&lane[i], &lane[j]));
+                permute_lane(&mut lane, &perm);
+            }
+        });
+    }
+
     /// Divides an array slice into two at an index along the first dimension.
     ///
     /// # Panics
@@ -541,7 +1219,8 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
     pub fn split_at(
         &self,
         mid: usize,
-    ) -> (View<T, Resize<Const<0>, S>, L>, View<T, Resize<Const<0>, S>, L>) {
+    ) -> (View<'_, T, Resize<Const<0>, S>, L::Inner>, View<'_, T, Resize<Const<0>, S>, L::Inner>)
+    {
         self.split_axis_at(Const::<0>, mid)
     }
 
@@ -554,7 +1233,10 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
     pub fn split_at_mut(
         &mut self,
         mid: usize,
-    ) -> (ViewMut<T, Resize<Const<0>, S>, L>, ViewMut<T, Resize<Const<0>, S>, L>) {
+    ) -> (
+        ViewMut<'_, T, Resize<Const<0>, S>, L::Inner>,
+        ViewMut<'_, T, Resize<Const<0>, S>, L::Inner>,
+    ) {
         self.split_axis_at_mut(Const::<0>, mid)
     }
 
@@ -572,7 +1254,8 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
         &self,
         axis: A,
         mid: usize,
-    ) -> (View<T, Resize<A, S>, Split<A, S, L>>, View<T, Resize<A, S>, Split<A, S, L>>) {
+    ) -> (View<'_, T, Resize<A, S>, Split<A, S, L>>, View<'_, T, Resize<A, S>, Split<A, S, L>>)
+    {
         unsafe { View::split_axis_at(self.as_ptr(), self.mapping(), axis, mid) }
     }
 
@@ -590,7 +1273,8 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
         &mut self,
         axis: A,
         mid: usize,
-    ) -> (ViewMut<T, Resize<A, S>, Split<A, S, L>>, ViewMut<T, Resize<A, S>, Split<A, S, L>>) {
+    ) -> (ViewMut<'_, T, Resize<A, S>, Split<A, S, L>>, ViewMut<'_, T, Resize<A, S>, Split<A, S, L>>)
+    {
         unsafe { ViewMut::split_axis_at(self.as_mut_ptr(), self.mapping(), axis, mid) }
     }
 
@@ -603,6 +1287,76 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
         self.mapping().stride(index)
     }
 
+    /// Returns an array with the sum of the elements along the specified dimension,
+    /// where the dimension is removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::{tensor, view};
+    ///
+    /// let t = tensor![[1, 2, 3], [4, 5, 6]];
+    ///
+    /// assert_eq!(t.sum_axis(0), view![5, 7, 9]);
+    /// assert_eq!(t.sum_axis_keepdims(1), view![[6], [15]]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension is out of bounds.
+    pub fn sum_axis<A: Axis>(&self, axis: A) -> <A::Remove<S> as Shape>::Owned<T>
+    where
+        T: Clone + Sum,
+    {
+        self.lanes(axis).map(|x| sum(&x)).eval()
+    }
+
+    /// Returns an array with the sum of the elements along the specified dimension,
+    /// where the dimension is kept with size one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension is out of bounds.
+    pub fn sum_axis_keepdims<A: Axis>(&self, axis: A) -> Tensor<T, Resize<A, S>>
+    where
+        T: Clone + Sum,
+    {
+        reduce_keepdims(self, axis, |x| sum(&x))
+    }
+
+    /// Returns an array with the subarrays along the specified dimension at the given
+    /// indices, cloning the elements.
+    ///
+    /// Indices can be repeated and in any order, and the resulting dimension has the
+    /// same size as the number of indices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::{tensor, view};
+    ///
+    /// let t = tensor![[1, 2, 3], [4, 5, 6]];
+    ///
+    /// assert_eq!(t.take(0, &[1, 1, 0]), view![[4, 5, 6], [4, 5, 6], [1, 2, 3]]);
+    /// assert_eq!(t.take(1, &[2, 0]), view![[3, 1], [6, 4]]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension or an index is out of bounds.
+    pub fn take<A: Axis>(&self, axis: A, indices: &[usize]) -> Tensor<T, Resize<A, S>>
+    where
+        T: Clone,
+    {
+        let index = axis.index(self.rank());
+        let inputs = indices.iter().map(|&i| self.axis_at(axis, i)).collect::<Vec<_>>();
+
+        let shape: Resize<A, S> = self.shape().resize_dim(index, indices.len());
+        let size = self.shape().with_dims(|dims| dims[index + 1..].iter().product());
+
+        FromExpression::from_expr(concat::interleave(shape, &inputs, vec![size; indices.len()]))
+    }
+
     /// Copies the array slice into a new array.
     pub fn to_array(&self) -> Array<T, S>
     where
@@ -648,7 +1402,7 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
 
     /// Returns a transposed array view of the array slice, where the dimensions
     /// are reversed.
-    pub fn transpose(&self) -> View<T, S::Reverse, <S::Tail as Shape>::Layout<L>> {
+    pub fn transpose(&self) -> View<'_, T, S::Reverse, <S::Tail as Shape>::Layout<L>> {
         let mapping = Mapping::transpose(self.mapping());
 
         unsafe { View::new_unchecked(self.as_ptr(), mapping) }
@@ -656,11 +1410,189 @@ impl<T, S: Shape, L: Layout> Slice<T, S, L> {
 
     /// Returns a mutable transposed array view of the array slice, where the dimensions
     /// are reversed.
-    pub fn transpose_mut(&mut self) -> ViewMut<T, S::Reverse, <S::Tail as Shape>::Layout<L>> {
+    pub fn transpose_mut(&mut self) -> ViewMut<'_, T, S::Reverse, <S::Tail as Shape>::Layout<L>> {
         let mapping = Mapping::transpose(self.mapping());
 
         unsafe { ViewMut::new_unchecked(self.as_mut_ptr(), mapping) }
     }
+
+    /// Tries to return a remapped array view of the array slice.
+    ///
+    /// # Errors
+    ///
+    /// If the shape is not matching static rank or constant-sized dimensions, or if
+    /// the memory layout is not compatible with the new array layout, then an error
+    /// is returned.
+    pub fn try_remap<R: Shape, K: Layout>(&self) -> Result<View<'_, T, R, K>, Error> {
+        let mapping = Mapping::try_remap(self.mapping())?;
+
+        Ok(unsafe { View::new_unchecked(self.as_ptr(), mapping) })
+    }
+
+    /// Tries to return a mutable remapped array view of the array slice.
+    ///
+    /// # Errors
+    ///
+    /// If the shape is not matching static rank or constant-sized dimensions, or if
+    /// the memory layout is not compatible with the new array layout, then an error
+    /// is returned.
+    pub fn try_remap_mut<R: Shape, K: Layout>(&mut self) -> Result<ViewMut<'_, T, R, K>, Error> {
+        let mapping = Mapping::try_remap(self.mapping())?;
+
+        Ok(unsafe { ViewMut::new_unchecked(self.as_mut_ptr(), mapping) })
+    }
+
+    /// Tries to return a reshaped array view of the array slice.
+    ///
+    /// At most one dimension can have dynamic size `usize::MAX`, and is then inferred
+    /// from the other dimensions and the array length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::{Error, view};
+    ///
+    /// let v = view![[1, 2, 3], [4, 5, 6]];
+    ///
+    /// assert_eq!(v.try_reshape([3, 2]).unwrap(), view![[1, 2], [3, 4], [5, 6]]);
+    /// assert_eq!(v.try_reshape([4, !0]).unwrap_err(), Error::ShapeMismatch);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the array length is changed, if the length overflows, or if the memory layout
+    /// is not compatible, then an error is returned.
+    pub fn try_reshape<I: IntoShape>(
+        &self,
+        shape: I,
+    ) -> Result<View<'_, T, I::IntoShape, L>, Error> {
+        let mapping = self.mapping().try_reshape(shape.into_shape())?;
+
+        Ok(unsafe { View::new_unchecked(self.as_ptr(), mapping) })
+    }
+
+    /// Tries to return a mutable reshaped array view of the array slice.
+    ///
+    /// At most one dimension can have dynamic size `usize::MAX`, and is then inferred
+    /// from the other dimensions and the array length.
+    ///
+    /// # Errors
+    ///
+    /// If the array length is changed, if the length overflows, or if the memory layout
+    /// is not compatible, then an error is returned.
+    pub fn try_reshape_mut<I: IntoShape>(
+        &mut self,
+        shape: I,
+    ) -> Result<ViewMut<'_, T, I::IntoShape, L>, Error> {
+        let mapping = self.mapping().try_reshape(shape.into_shape())?;
+
+        Ok(unsafe { ViewMut::new_unchecked(self.as_mut_ptr(), mapping) })
+    }
+
+    /// Tries to divide an array slice into two at an index along the first dimension.
+    ///
+    /// # Errors
+    ///
+    /// If the rank is not at least 1, or if the split point is larger than the number
+    /// of elements in that dimension, then an error is returned.
+    pub fn try_split_at(
+        &self,
+        mid: usize,
+    ) -> Result<
+        (View<'_, T, Resize<Const<0>, S>, L::Inner>, View<'_, T, Resize<Const<0>, S>, L::Inner>),
+        Error,
+    > {
+        check_split(self.shape(), Const::<0>, mid)?;
+
+        Ok(self.split_at(mid))
+    }
+
+    /// Tries to divide a mutable array slice into two at an index along the first dimension.
+    ///
+    /// # Errors
+    ///
+    /// If the rank is not at least 1, or if the split point is larger than the number
+    /// of elements in that dimension, then an error is returned.
+    pub fn try_split_at_mut(
+        &mut self,
+        mid: usize,
+    ) -> Result<
+        (
+            ViewMut<'_, T, Resize<Const<0>, S>, L::Inner>,
+            ViewMut<'_, T, Resize<Const<0>, S>, L::Inner>,
+        ),
+        Error,
+    > {
+        check_split(self.shape(), Const::<0>, mid)?;
+
+        Ok(self.split_at_mut(mid))
+    }
+
+    /// Tries to divide an array slice into two at an index along the specified dimension.
+    ///
+    /// # Errors
+    ///
+    /// If the dimension is out of bounds, or if the split point is larger than the number
+    /// of elements in that dimension, then an error is returned.
+    pub fn try_split_axis_at<A: Axis>(
+        &self,
+        axis: A,
+        mid: usize,
+    ) -> Result<
+        (View<'_, T, Resize<A, S>, Split<A, S, L>>, View<'_, T, Resize<A, S>, Split<A, S, L>>),
+        Error,
+    > {
+        check_split(self.shape(), axis, mid)?;
+
+        Ok(self.split_axis_at(axis, mid))
+    }
+
+    /// Tries to divide a mutable array slice into two at an index along the specified
+    /// dimension.
+    ///
+    /// # Errors
+    ///
+    /// If the dimension is out of bounds, or if the split point is larger than the number
+    /// of elements in that dimension, then an error is returned.
+    pub fn try_split_axis_at_mut<A: Axis>(
+        &mut self,
+        axis: A,
+        mid: usize,
+    ) -> Result<
+        (
+            ViewMut<'_, T, Resize<A, S>, Split<A, S, L>>,
+            ViewMut<'_, T, Resize<A, S>, Split<A, S, L>>,
+        ),
+        Error,
+    > {
+        check_split(self.shape(), axis, mid)?;
+
+        Ok(self.split_axis_at_mut(axis, mid))
+    }
+
+    /// Returns an expression that gives overlapping array views of the specified size,
+    /// where the outer shape is the number of window positions in each dimension.
+    ///
+    /// If the window is larger than the array in some dimension, the expression is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::expr::Expression;
+    /// use mdarray::{tensor, view};
+    ///
+    /// let t = tensor![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+    ///
+    /// assert_eq!(t.windows([2, 2]).shape(), &(2, 2));
+    /// assert_eq!(t.windows([2, 2]).map(|x| x.iter().sum::<i32>()).eval(), view![[12, 16], [24, 28]]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rank is not matching, or if the window size is zero in some dimension.
+    pub fn windows<I: IntoShape>(&self, size: I) -> Windows<'_, T, S, L, I::IntoShape> {
+        Windows::new(self, size.into_shape())
+    }
 }
 
 impl<T, L: Layout> Slice<T, DynRank, L> {
@@ -668,6 +1600,143 @@ impl<T, L: Layout> Slice<T, DynRank, L> {
     pub fn dims(&self) -> &[usize] {
         self.mapping().dims()
     }
+
+    /// Returns an array view with a new dimension of size one inserted at the specified index.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::IndexOutOfBounds` if the index is larger than the rank.
+    pub fn insert_axis(&self, index: usize) -> Result<View<'_, T, DynRank, L>, Error> {
+        Ok(self.reshape(insert_dim(self.dims(), index)?))
+    }
+
+    /// Returns a mutable array view with a new dimension of size one inserted at the
+    /// specified index.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::IndexOutOfBounds` if the index is larger than the rank.
+    pub fn insert_axis_mut(&mut self, index: usize) -> Result<ViewMut<'_, T, DynRank, L>, Error> {
+        Ok(self.reshape_mut(insert_dim(self.dims(), index)?))
+    }
+
+    /// Returns an array view with the dimensions permuted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::RankMismatch` if the length of the permutation is not equal to
+    /// the rank, `Error::IndexOutOfBounds` if a dimension is out of bounds, and
+    /// `Error::InvalidPermutation` if a dimension is given more than once.
+    pub fn permute_dyn(&self, perm: &[usize]) -> Result<View<'_, T, DynRank, Strided>, Error> {
+        check_permutation(perm, self.rank())?;
+
+        Ok(self.permute(perm))
+    }
+
+    /// Returns a mutable array view with the dimensions permuted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::RankMismatch` if the length of the permutation is not equal to
+    /// the rank, `Error::IndexOutOfBounds` if a dimension is out of bounds, and
+    /// `Error::InvalidPermutation` if a dimension is given more than once.
+    pub fn permute_dyn_mut(
+        &mut self,
+        perm: &[usize],
+    ) -> Result<ViewMut<'_, T, DynRank, Strided>, Error> {
+        check_permutation(perm, self.rank())?;
+
+        Ok(self.permute_mut(perm))
+    }
+
+    /// Returns an array view with the dimension of size one at the specified index removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::IndexOutOfBounds` if the index is out of bounds, and
+    /// `Error::ShapeMismatch` if the size of the dimension is not one.
+    pub fn remove_axis(&self, index: usize) -> Result<View<'_, T, DynRank, L>, Error> {
+        Ok(self.reshape(remove_dim(self.dims(), index)?))
+    }
+
+    /// Returns a mutable array view with the dimension of size one at the specified
+    /// index removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::IndexOutOfBounds` if the index is out of bounds, and
+    /// `Error::ShapeMismatch` if the size of the dimension is not one.
+    pub fn remove_axis_mut(&mut self, index: usize) -> Result<ViewMut<'_, T, DynRank, L>, Error> {
+        Ok(self.reshape_mut(remove_dim(self.dims(), index)?))
+    }
+
+    /// Returns an array view with all dimensions of size one removed.
+    pub fn squeeze(&self) -> View<'_, T, DynRank, L> {
+        self.reshape(squeeze_dims(self.dims()))
+    }
+
+    /// Returns a mutable array view with all dimensions of size one removed.
+    pub fn squeeze_mut(&mut self) -> ViewMut<'_, T, DynRank, L> {
+        self.reshape_mut(squeeze_dims(self.dims()))
+    }
+
+    /// Returns an array view with two dimensions interchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::IndexOutOfBounds` if a dimension is out of bounds.
+    pub fn swap_axes(&self, i: usize, j: usize) -> Result<View<'_, T, DynRank, Strided>, Error> {
+        Ok(self.permute(swap_dims(self.rank(), i, j)?))
+    }
+
+    /// Returns a mutable array view with two dimensions interchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::IndexOutOfBounds` if a dimension is out of bounds.
+    pub fn swap_axes_mut(
+        &mut self,
+        i: usize,
+        j: usize,
+    ) -> Result<ViewMut<'_, T, DynRank, Strided>, Error> {
+        Ok(self.permute_mut(swap_dims(self.rank(), i, j)?))
+    }
+}
+
+impl<T, S: Shape> Slice<T, S, Dense> {
+    /// Returns an array view in column-major order of the array slice, where the
+    /// dimensions are reversed.
+    pub fn col_major(&self) -> View<'_, T, S::Reverse, DenseColMajor> {
+        let mapping = Mapping::transpose(self.mapping());
+
+        unsafe { View::new_unchecked(self.as_ptr(), mapping) }
+    }
+
+    /// Returns a mutable array view in column-major order of the array slice, where
+    /// the dimensions are reversed.
+    pub fn col_major_mut(&mut self) -> ViewMut<'_, T, S::Reverse, DenseColMajor> {
+        let mapping = Mapping::transpose(self.mapping());
+
+        unsafe { ViewMut::new_unchecked(self.as_mut_ptr(), mapping) }
+    }
+}
+
+impl<T, S: Shape> Slice<T, S, DenseColMajor> {
+    /// Returns an array view in row-major order of the array slice, where the
+    /// dimensions are reversed.
+    pub fn row_major(&self) -> View<'_, T, S::Reverse> {
+        let mapping = Mapping::transpose(self.mapping());
+
+        unsafe { View::new_unchecked(self.as_ptr(), mapping) }
+    }
+
+    /// Returns a mutable array view in row-major order of the array slice, where
+    /// the dimensions are reversed.
+    pub fn row_major_mut(&mut self) -> ViewMut<'_, T, S::Reverse> {
+        let mapping = Mapping::transpose(self.mapping());
+
+        unsafe { ViewMut::new_unchecked(self.as_mut_ptr(), mapping) }
+    }
 }
 
 impl<T, S: Shape> Slice<T, S, Strided> {
@@ -719,7 +1788,7 @@ macro_rules! impl_view {
             pub fn view<$($abc: DimIndex),+>(
                 &self,
                 $($idx: $abc),+
-            ) -> View<
+            ) -> View<'_,
                 T,
                 <($($abc,)+) as ViewIndex>::Shape<($($xyz,)+)>,
                 <($($abc,)+) as ViewIndex>::Layout<L>,
@@ -735,7 +1804,7 @@ macro_rules! impl_view {
             pub fn view_mut<$($abc: DimIndex),+>(
                 &mut self,
                 $($idx: $abc),+,
-            ) -> ViewMut<
+            ) -> ViewMut<'_,
                 T,
                 <($($abc,)+) as ViewIndex>::Shape<($($xyz,)+)>,
                 <($($abc,)+) as ViewIndex>::Layout<L>,
@@ -835,7 +1904,7 @@ impl_as_mut_ref!((X, Y, Z, W, U), [[[[[T; U]; W]; Z]; Y]; X]);
 impl_as_mut_ref!((X, Y, Z, W, U, V), [[[[[[T; V]; U]; W]; Z]; Y]; X]);
 
 impl<T: Debug, S: Shape, L: Layout> Debug for Slice<T, S, L> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         if self.rank() == 0 {
             self[[]].fmt(f)
         } else {
@@ -919,6 +1988,40 @@ impl<T: Clone, S: Shape> ToOwned for Slice<T, S> {
     }
 }
 
+pub(crate) fn check_permutation(perm: &[usize], rank: usize) -> Result<(), Error> {
+    if perm.len() != rank {
+        return Err(Error::RankMismatch);
+    }
+
+    for (i, &x) in perm.iter().enumerate() {
+        if x >= rank {
+            return Err(Error::IndexOutOfBounds);
+        }
+
+        if perm[..i].contains(&x) {
+            return Err(Error::InvalidPermutation);
+        }
+    }
+
+    Ok(())
+}
+
+pub(crate) fn check_split<S: Shape, A: Axis>(shape: &S, axis: A, mid: usize) -> Result<(), Error> {
+    if mid > shape.dim(axis.try_index(shape.rank())?) {
+        return Err(Error::IndexOutOfBounds);
+    }
+
+    Ok(())
+}
+
+fn argmax<T: PartialOrd, D: Dim, L: Layout>(lane: &Slice<T, (D,), L>) -> usize {
+    lane.iter().enumerate().fold(0, |acc, (i, x)| if *x > lane[acc] { i } else { acc })
+}
+
+fn argmin<T: PartialOrd, D: Dim, L: Layout>(lane: &Slice<T, (D,), L>) -> usize {
+    lane.iter().enumerate().fold(0, |acc, (i, x)| if *x < lane[acc] { i } else { acc })
+}
+
 fn contains<T: PartialEq, S: Shape, L: Layout>(this: &Slice<T, S, L>, value: &T) -> bool {
     if L::IS_DENSE {
         this.remap::<S, _>()[..].contains(value)
@@ -928,3 +2031,106 @@ fn contains<T: PartialEq, S: Shape, L: Layout>(this: &Slice<T, S, L>, value: &T)
         this.outer_expr().into_iter().any(|x| x.contains(value))
     }
 }
+
+fn count<T: Clone + Product + Sum>(len: usize) -> T {
+    // Convert the length from the binary digits using only sums, which avoids requiring a
+    // numeric conversion trait. The result is exact if the length is representable by the
+    // element type, e.g. up to 2^24 for f32, and is otherwise rounded.
+    let mut count: T = iter::empty().sum();
+
+    for i in (0..usize::BITS - len.leading_zeros()).rev() {
+        count = [count.clone(), count].into_iter().sum();
+
+        if (len >> i) & 1 == 1 {
+            count = [count, iter::empty().product()].into_iter().sum();
+        }
+    }
+
+    count
+}
+
+pub(crate) fn insert_dim(dims: &[usize], index: usize) -> Result<Vec<usize>, Error> {
+    if index > dims.len() {
+        return Err(Error::IndexOutOfBounds);
+    }
+
+    Ok([&dims[..index], &[1], &dims[index..]].concat())
+}
+
+fn max<T: Clone + PartialOrd, D: Dim, L: Layout>(lane: &Slice<T, (D,), L>) -> T {
+    lane.iter().fold(&lane[0], |acc, x| if x > acc { x } else { acc }).clone()
+}
+
+fn min<T: Clone + PartialOrd, D: Dim, L: Layout>(lane: &Slice<T, (D,), L>) -> T {
+    lane.iter().fold(&lane[0], |acc, x| if x < acc { x } else { acc }).clone()
+}
+
+// Moves the elements in a lane so that position `i` gets the element at `perm[i]`,
+// by following each cycle in the permutation and swapping elements along it.
+fn permute_lane<T, D: Dim, L: Layout>(lane: &mut Slice<T, (D,), L>, perm: &[usize]) {
+    let ptrs = lane.iter_mut().map(|x| x as *mut T).collect::<Vec<_>>();
+    let mut visited = vec![false; perm.len()];
+
+    for start in 0..perm.len() {
+        let mut i = start;
+
+        while !visited[i] && perm[i] != start {
+            unsafe { ptr::swap(ptrs[i], ptrs[perm[i]]) };
+
+            visited[i] = true;
+            i = perm[i];
+        }
+
+        visited[i] = true;
+    }
+}
+
+fn prod<T: Clone + Product, D: Dim, L: Layout>(lane: &Slice<T, (D,), L>) -> T {
+    lane.iter().cloned().product()
+}
+
+fn reduce_keepdims<'a, T, U, S: Shape, L: Layout, A: Axis, F>(
+    this: &'a Slice<T, S, L>,
+    axis: A,
+    f: F,
+) -> Tensor<U, Resize<A, S>>
+where
+    F: FnMut(View<'a, T, (A::Dim<S>,), Keep<A, S, L>>) -> U,
+{
+    let shape: Resize<A, S> = this.shape().resize_dim(axis.index(this.rank()), 1);
+    let tensor: Tensor<U, A::Remove<S>> = FromExpression::from_expr(this.lanes(axis).map(f));
+
+    tensor.into_shape(shape)
+}
+
+pub(crate) fn remove_dim(dims: &[usize], index: usize) -> Result<Vec<usize>, Error> {
+    if index >= dims.len() {
+        return Err(Error::IndexOutOfBounds);
+    }
+
+    if dims[index] != 1 {
+        return Err(Error::ShapeMismatch);
+    }
+
+    Ok([&dims[..index], &dims[index + 1..]].concat())
+}
+
+pub(crate) fn squeeze_dims(dims: &[usize]) -> Vec<usize> {
+    dims.iter().copied().filter(|&size| size != 1).collect()
+}
+
+fn sum<T: Clone + Sum, D: Dim, L: Layout>(lane: &Slice<T, (D,), L>) -> T {
+    lane.iter().cloned().sum()
+}
+
+pub(crate) fn swap_dims(rank: usize, i: usize, j: usize) -> Result<Vec<usize>, Error> {
+    if i >= rank || j >= rank {
+        return Err(Error::IndexOutOfBounds);
+    }
+
+    let mut perm = (0..rank).collect::<Vec<_>>();
+
+    perm.swap(i, j);
+
+    Ok(perm)
+}
@@ -9,6 +9,8 @@ use core::mem::{self, ManuallyDrop, MaybeUninit};
 use core::ops::{Deref, DerefMut, Index, IndexMut, RangeBounds};
 use core::{ptr, slice};
 
+#[cfg(feature = "num-traits")]
+use num_traits::{Float, Num, NumCast, One, Zero};
 #[cfg(feature = "std")]
 use std::collections::TryReserveError;
 
@@ -16,14 +18,15 @@ use std::collections::TryReserveError;
 use crate::alloc::{Allocator, Global};
 use crate::array::Array;
 use crate::dim::{Const, Dim, Dyn};
+use crate::error::Error;
 use crate::expr::{self, Drain, IntoExpr, Iter, Map, Zip};
 use crate::expr::{Apply, Expression, FromExpression, IntoExpression};
-use crate::index::SliceIndex;
-use crate::layout::{Dense, Layout};
+use crate::index::{Axis, SliceIndex};
+use crate::layout::{Dense, Layout, Strided};
 use crate::mapping::{DenseMapping, Mapping};
 use crate::raw_tensor::RawTensor;
-use crate::shape::{ConstShape, DynRank, IntoShape, Rank, Shape};
-use crate::slice::Slice;
+use crate::shape::{self, ConstShape, DynRank, IntoShape, Rank, Shape};
+use crate::slice::{Slice, check_permutation, insert_dim, remove_dim, squeeze_dims, swap_dims};
 use crate::traits::{IntoCloned, Owned};
 use crate::view::{View, ViewMut};
 
@@ -68,6 +71,60 @@ impl<T, S: Shape, A: Allocator> Tensor<T, S, A> {
         self.expand(other.drain(..));
     }
 
+    /// Appends an expression to the array along the specified dimension, cloning elements
+    /// if needed.
+    ///
+    /// Existing elements are moved to make room for the new elements, which requires
+    /// a new allocation unless the dimension is the first one. If the array is empty,
+    /// it is resized to match the shape of the expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::index::Rows;
+    /// use mdarray::{tensor, view};
+    ///
+    /// let mut t = tensor![[1, 2], [3, 4]];
+    ///
+    /// t.append_axis(Rows, view![[5], [6]]);
+    ///
+    /// assert_eq!(t, view![[1, 2, 5], [3, 4, 6]]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension is out of bounds or not dynamically-sized, if the rank
+    /// does not match, or if the array is not empty and the shapes are not matching
+    /// except in the specified dimension.
+    pub fn append_axis<X: Axis, I: IntoExpression<Item: IntoCloned<T>>>(&mut self, axis: X, expr: I)
+    where
+        A: Clone,
+    {
+        let expr = expr.into_expr();
+        let index = axis.index(self.rank());
+
+        assert!(X::Dim::<S>::SIZE.is_none(), "dimension not dynamically-sized");
+        assert!(expr.rank() == self.rank(), "invalid rank");
+
+        let mut shape = self.shape().clone();
+
+        if self.is_empty() {
+            expr.shape().with_dims(|src| shape.with_mut_dims(|dims| dims.copy_from_slice(src)));
+        } else {
+            for i in 0..self.rank() {
+                assert!(i == index || expr.dim(i) == self.dim(i), "shape mismatch");
+            }
+
+            shape.with_mut_dims(|dims| dims[index] += expr.dim(index));
+        }
+
+        let mut iter = expr.into_iter();
+
+        shape.with_dims(|dims| {
+            self.tensor.resize_with(dims, || iter.next().expect("length mismatch").into_cloned())
+        });
+    }
+
     /// Returns the number of elements the array can hold without reallocating.
     pub fn capacity(&self) -> usize {
         self.tensor.capacity()
@@ -100,7 +157,7 @@ impl<T, S: Shape, A: Allocator> Tensor<T, S, A> {
     ///
     /// Panics if the rank is not at least 1, or if the first dimension
     /// is not dynamically-sized.
-    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> IntoExpr<Drain<T, S, A>> {
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> IntoExpr<Drain<'_, T, S, A>> {
         assert!(self.rank() > 0, "invalid rank");
         assert!(S::Head::SIZE.is_none(), "first dimension not dynamically-sized");
 
@@ -115,12 +172,15 @@ impl<T, S: Shape, A: Allocator> Tensor<T, S, A> {
     /// Appends an expression to the array along the first dimension with broadcasting,
     /// cloning elements if needed.
     ///
-    /// If the array is empty, it is reshaped to match the shape of the expression.
+    /// The expression is broadcast to the inner dimensions of the array, where missing
+    /// dimensions are treated as size one. If the array is empty, it is reshaped to match
+    /// the shape of the expression.
     ///
     /// # Panics
     ///
-    /// Panics if the inner dimensions do not match, if the rank is not the same and
-    /// at least 1, or if the first dimension is not dynamically-sized.
+    /// Panics if the inner dimensions cannot be broadcast, if the rank of the expression
+    /// is larger than the array rank, if the rank is not at least 1, or if the first
+    /// dimension is not dynamically-sized.
     pub fn expand<I: IntoExpression<Item: IntoCloned<T>>>(&mut self, expr: I) {
         assert!(self.rank() > 0, "invalid rank");
         assert!(S::Head::SIZE.is_none(), "first dimension not dynamically-sized");
@@ -131,26 +191,55 @@ impl<T, S: Shape, A: Allocator> Tensor<T, S, A> {
         if len > 0 {
             unsafe {
                 self.tensor.with_mut_parts(|vec, mapping| {
-                    vec.reserve(len);
+                    if mapping.is_empty() {
+                        vec.reserve(len);
 
-                    expr.shape().with_dims(|src| {
-                        if mapping.is_empty() {
+                        expr.shape().with_dims(|src| {
                             if src.len() == mapping.rank() {
                                 mapping.shape_mut().with_mut_dims(|dims| dims.copy_from_slice(src));
                             } else {
                                 *mapping = DenseMapping::new(Shape::from_dims(src));
                             }
-                        } else {
-                            mapping.shape_mut().with_mut_dims(|dims| {
-                                assert!(src.len() == dims.len(), "invalid rank");
-                                assert!(src[1..] == dims[1..], "inner dimensions mismatch");
+                        });
 
-                                dims[0] += src[0];
-                            });
-                        }
-                    });
+                        expr.clone_into_vec(vec);
+                    } else {
+                        let mut shape = mapping.shape().clone();
+
+                        let is_broadcast = expr.shape().with_dims(|src| {
+                            shape.with_mut_dims(|dims| {
+                                assert!(src.len() <= dims.len(), "invalid rank");
+
+                                let offset = dims.len() - src.len();
+
+                                for i in 1..dims.len() {
+                                    let size = if i < offset { 1 } else { src[i - offset] };
+
+                                    assert!(
+                                        size == dims[i] || size == 1,
+                                        "inner dimensions mismatch"
+                                    );
+                                }
+
+                                dims[0] = if offset > 0 { 1 } else { src[0] };
+
+                                offset > 0 || src[1..] != dims[1..]
+                            })
+                        });
 
-                    expr.clone_into_vec(vec);
+                        vec.reserve(shape.len());
+
+                        mapping.shape_mut().with_mut_dims(|dims| dims[0] += shape.dim(0));
+
+                        if is_broadcast {
+                            expr::from_elem(shape, ())
+                                .zip(expr)
+                                .map(|(_, x)| x)
+                                .clone_into_vec(vec);
+                        } else {
+                            expr.clone_into_vec(vec);
+                        }
+                    }
                 });
             }
         }
@@ -295,6 +384,11 @@ impl<T, S: Shape, A: Allocator> Tensor<T, S, A> {
     }
 
     /// Resizes the array to the new shape, creating new elements with the given value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rank is changed, or if the number of elements or the size in bytes
+    /// exceeds `isize::MAX`.
     pub fn resize(&mut self, new_dims: &[usize], value: T)
     where
         T: Clone,
@@ -304,6 +398,11 @@ impl<T, S: Shape, A: Allocator> Tensor<T, S, A> {
     }
 
     /// Resizes the array to the new shape, creating new elements from the given closure.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rank is changed, or if the number of elements or the size in bytes
+    /// exceeds `isize::MAX`.
     pub fn resize_with<F: FnMut() -> T>(&mut self, new_dims: &[usize], f: F)
     where
         A: Clone,
@@ -371,6 +470,99 @@ impl<T, S: Shape, A: Allocator> Tensor<T, S, A> {
         }
     }
 
+    /// Tries to convert the array into a remapped array.
+    ///
+    /// # Errors
+    ///
+    /// If the shape is not matching static rank or constant-sized dimensions, then
+    /// an error is returned together with the original array.
+    pub fn try_into_mapping<R: Shape>(self) -> Result<Tensor<T, R, A>, (Self, Error)> {
+        match Mapping::try_remap(self.mapping()) {
+            Ok(mapping) => {
+                let (vec, _) = self.tensor.into_parts();
+
+                Ok(unsafe { Tensor::from_parts(vec, mapping) })
+            }
+            Err(err) => Err((self, err)),
+        }
+    }
+
+    /// Tries to convert the array into a reshaped array, which must have the same length.
+    ///
+    /// At most one dimension can have dynamic size `usize::MAX`, and is then inferred
+    /// from the other dimensions and the array length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::{Error, tensor, view};
+    ///
+    /// let t = tensor![[1, 2, 3], [4, 5, 6]];
+    ///
+    /// assert_eq!(t.clone().try_into_shape([3, 2]).unwrap(), view![[1, 2], [3, 4], [5, 6]]);
+    /// assert_eq!(t.try_into_shape([4, !0]).unwrap_err().1, Error::ShapeMismatch);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the array length is changed, or if the length overflows, then an error is
+    /// returned together with the original array.
+    pub fn try_into_shape<I: IntoShape>(
+        self,
+        shape: I,
+    ) -> Result<Tensor<T, I::IntoShape, A>, (Self, Error)> {
+        match self.mapping().try_reshape(shape.into_shape()) {
+            Ok(mapping) => {
+                let (vec, _) = self.tensor.into_parts();
+
+                Ok(unsafe { Tensor::from_parts(vec, mapping) })
+            }
+            Err(err) => Err((self, err)),
+        }
+    }
+
+    /// Tries to resize the array to the new shape, creating new elements with the given value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::RankMismatch` if the rank is changed, and `Error::Overflow` if the
+    /// number of elements or the size in bytes exceeds `isize::MAX`. The array is then
+    /// not modified.
+    pub fn try_resize(&mut self, new_dims: &[usize], value: T) -> Result<(), Error>
+    where
+        T: Clone,
+        A: Clone,
+    {
+        self.try_resize_with(new_dims, || value.clone())
+    }
+
+    /// Tries to resize the array to the new shape, creating new elements from the
+    /// given closure.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::RankMismatch` if the rank is changed, and `Error::Overflow` if the
+    /// number of elements or the size in bytes exceeds `isize::MAX`. The array is then
+    /// not modified.
+    pub fn try_resize_with<F: FnMut() -> T>(
+        &mut self,
+        new_dims: &[usize],
+        f: F,
+    ) -> Result<(), Error>
+    where
+        A: Clone,
+    {
+        if new_dims.len() != self.rank() {
+            return Err(Error::RankMismatch);
+        }
+
+        _ = shape::try_len::<T>(new_dims)?;
+
+        self.tensor.resize_with(new_dims, f);
+
+        Ok(())
+    }
+
     /// Tries to reserve capacity for at least the additional number of elements in the array.
     ///
     /// # Errors
@@ -396,7 +588,7 @@ impl<T, S: Shape, A: Allocator> Tensor<T, S, A> {
         alloc: A,
     ) -> Tensor<MaybeUninit<T>, S, A> {
         let shape = shape.into_shape();
-        let len = shape.checked_len().expect("invalid length");
+        let len = shape.try_len::<T>().expect("invalid length");
 
         let vec = Vec::from(Box::new_uninit_slice_in(len, alloc));
 
@@ -435,7 +627,7 @@ impl<T, S: Shape, A: Allocator> Tensor<T, S, A> {
     #[cfg(not(feature = "nightly"))]
     fn from_expr<E: Expression<Item = T, Shape = S>>(expr: E) -> Self {
         let shape = expr.shape().clone();
-        let mut vec = Vec::with_capacity(shape.len());
+        let mut vec = Vec::with_capacity(shape.try_len::<T>().expect("invalid length"));
 
         expr.clone_into_vec(&mut vec);
 
@@ -448,7 +640,7 @@ impl<T, S: Shape, A: Allocator> Tensor<T, S, A> {
         E: Expression<Item = T, Shape = S>,
     {
         let shape = expr.shape().clone();
-        let mut vec = Vec::with_capacity_in(shape.len(), alloc);
+        let mut vec = Vec::with_capacity_in(shape.try_len::<T>().expect("invalid length"), alloc);
 
         expr.clone_into_vec(&mut vec);
 
@@ -504,9 +696,116 @@ impl<T, S: Shape, A: Allocator> Tensor<T, S, A> {
     }
 }
 
+impl<T, A: Allocator> Tensor<T, DynRank, A> {
+    /// Inserts a new dimension of size one at the specified index.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::IndexOutOfBounds` if the index is larger than the rank.
+    pub fn insert_axis_in_place(&mut self, index: usize) -> Result<(), Error> {
+        let dims = insert_dim(self.dims(), index)?;
+
+        unsafe {
+            self.tensor.set_mapping(DenseMapping::new(Shape::from_dims(&dims)));
+        }
+
+        Ok(())
+    }
+
+    /// Permutes the dimensions of the array, moving the elements to the new positions.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::RankMismatch` if the length of the permutation is not equal to
+    /// the rank, `Error::IndexOutOfBounds` if a dimension is out of bounds, and
+    /// `Error::InvalidPermutation` if a dimension is given more than once.
+    pub fn permute_dyn_in_place(&mut self, perm: &[usize]) -> Result<(), Error>
+    where
+        A: Clone,
+    {
+        check_permutation(perm, self.rank())?;
+
+        self.permute_elements(perm);
+
+        Ok(())
+    }
+
+    /// Removes the dimension of size one at the specified index.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::IndexOutOfBounds` if the index is out of bounds, and
+    /// `Error::ShapeMismatch` if the size of the dimension is not one.
+    pub fn remove_axis_in_place(&mut self, index: usize) -> Result<(), Error> {
+        let dims = remove_dim(self.dims(), index)?;
+
+        unsafe {
+            self.tensor.set_mapping(DenseMapping::new(Shape::from_dims(&dims)));
+        }
+
+        Ok(())
+    }
+
+    /// Removes all dimensions of size one.
+    pub fn squeeze_in_place(&mut self) {
+        let dims = squeeze_dims(self.dims());
+
+        unsafe {
+            self.tensor.set_mapping(DenseMapping::new(Shape::from_dims(&dims)));
+        }
+    }
+
+    /// Interchanges two dimensions of the array, moving the elements to the new positions.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::IndexOutOfBounds` if a dimension is out of bounds.
+    pub fn swap_axes_in_place(&mut self, i: usize, j: usize) -> Result<(), Error>
+    where
+        A: Clone,
+    {
+        self.permute_elements(&swap_dims(self.rank(), i, j)?);
+
+        Ok(())
+    }
+
+    fn permute_elements(&mut self, perm: &[usize])
+    where
+        A: Clone,
+    {
+        unsafe {
+            self.tensor.with_mut_parts(|vec, mapping| {
+                let view = View::<T, DynRank, Strided>::new_unchecked(
+                    vec.as_ptr(),
+                    Mapping::permute(mapping, perm),
+                );
+
+                #[cfg(not(feature = "nightly"))]
+                let mut new_vec = Vec::with_capacity(vec.capacity());
+                #[cfg(feature = "nightly")]
+                let mut new_vec = Vec::with_capacity_in(vec.capacity(), vec.allocator().clone());
+
+                // Move the elements in the permuted order, which cannot panic.
+                for x in view.iter() {
+                    new_vec.push(ptr::read(x));
+                }
+
+                *mapping = DenseMapping::new(view.shape().clone());
+
+                vec.set_len(0);
+                *vec = new_vec;
+            });
+        }
+    }
+}
+
 #[cfg(not(feature = "nightly"))]
 impl<T, S: Shape> Tensor<T, S> {
     /// Creates an array from the given element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of elements or the size in bytes exceeds `isize::MAX`.
     pub fn from_elem<I: IntoShape<IntoShape = S>>(shape: I, elem: T) -> Self
     where
         T: Clone,
@@ -515,6 +814,10 @@ impl<T, S: Shape> Tensor<T, S> {
     }
 
     /// Creates an array with the results from the given function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of elements or the size in bytes exceeds `isize::MAX`.
     pub fn from_fn<I: IntoShape<IntoShape = S>, F>(shape: I, f: F) -> Self
     where
         F: FnMut(&[usize]) -> T,
@@ -551,9 +854,13 @@ impl<T, S: Shape> Tensor<T, S> {
     }
 
     /// Creates an array with uninitialized elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of elements or the size in bytes exceeds `isize::MAX`.
     pub fn uninit<I: IntoShape<IntoShape = S>>(shape: I) -> Tensor<MaybeUninit<T>, S> {
         let shape = shape.into_shape();
-        let len = shape.checked_len().expect("invalid length");
+        let len = shape.try_len::<T>().expect("invalid length");
 
         let vec = Vec::from(Box::new_uninit_slice(len));
 
@@ -574,6 +881,10 @@ impl<T, S: Shape> Tensor<T, S> {
     /// Creates an array with elements set to zero.
     ///
     /// Zero elements are created using `Default::default()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of elements or the size in bytes exceeds `isize::MAX`.
     pub fn zeros<I: IntoShape<IntoShape = S>>(shape: I) -> Self
     where
         T: Default,
@@ -591,6 +902,10 @@ impl<T, S: Shape> Tensor<T, S> {
 #[cfg(feature = "nightly")]
 impl<T, S: Shape> Tensor<T, S> {
     /// Creates an array from the given element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of elements or the size in bytes exceeds `isize::MAX`.
     pub fn from_elem<I: IntoShape<IntoShape = S>>(shape: I, elem: T) -> Self
     where
         T: Clone,
@@ -599,6 +914,10 @@ impl<T, S: Shape> Tensor<T, S> {
     }
 
     /// Creates an array with the results from the given function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of elements or the size in bytes exceeds `isize::MAX`.
     pub fn from_fn<I: IntoShape<IntoShape = S>, F>(shape: I, f: F) -> Self
     where
         F: FnMut(&[usize]) -> T,
@@ -632,6 +951,10 @@ impl<T, S: Shape> Tensor<T, S> {
     }
 
     /// Creates an array with uninitialized elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of elements or the size in bytes exceeds `isize::MAX`.
     pub fn uninit<I: IntoShape<IntoShape = S>>(shape: I) -> Tensor<MaybeUninit<T>, S> {
         Self::uninit_in(shape, Global)
     }
@@ -648,6 +971,10 @@ impl<T, S: Shape> Tensor<T, S> {
     /// Creates an array with elements set to zero.
     ///
     /// Zero elements are created using `Default::default()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of elements or the size in bytes exceeds `isize::MAX`.
     pub fn zeros<I: IntoShape<IntoShape = S>>(shape: I) -> Self
     where
         T: Default,
@@ -656,6 +983,216 @@ impl<T, S: Shape> Tensor<T, S> {
     }
 }
 
+impl<T, S: Shape> Tensor<T, S> {
+    /// Creates an array with the reversed shape from the given element.
+    ///
+    /// The array is allocated with the dimensions reversed, so that `col_major` gives
+    /// a view in column-major order with the specified shape.
+    pub fn from_elem_transposed<I: IntoShape<IntoShape: Shape<Reverse = S>>>(
+        shape: I,
+        elem: T,
+    ) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_elem(shape.into_shape().reverse(), elem)
+    }
+
+    /// Creates an array with the reversed shape with the results from the given function.
+    ///
+    /// The array is allocated with the dimensions reversed, so that `col_major` gives
+    /// a view in column-major order with the specified shape. The function is called
+    /// with indices for the specified shape, in the order elements are stored in memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::{DenseColMajor, Tensor, View, view};
+    ///
+    /// let a = Tensor::from_fn_transposed([2, 3], |i| 10 * i[0] + i[1]);
+    /// let v: View<_, _, DenseColMajor> = a.col_major();
+    ///
+    /// assert_eq!(a.shape(), &(3, 2));
+    /// assert_eq!(a[..], [0, 10, 1, 11, 2, 12]);
+    /// assert_eq!(v, view![[0, 1, 2], [10, 11, 12]]);
+    /// ```
+    pub fn from_fn_transposed<I: IntoShape<IntoShape: Shape<Reverse = S>>, F>(
+        shape: I,
+        mut f: F,
+    ) -> Self
+    where
+        F: FnMut(&[usize]) -> T,
+    {
+        let shape = shape.into_shape();
+        let mut index = shape.new_dims::<usize>();
+
+        Self::from_fn(shape.reverse(), |i| {
+            for (x, y) in index.as_mut().iter_mut().zip(i.iter().rev()) {
+                *x = *y;
+            }
+
+            f(index.as_ref())
+        })
+    }
+
+    /// Creates an array with elements set to one.
+    #[cfg(feature = "num-traits")]
+    pub fn ones<I: IntoShape<IntoShape = S>>(shape: I) -> Self
+    where
+        T: Clone + One,
+    {
+        Self::from_elem(shape, T::one())
+    }
+
+    /// Tries to create an array from the given element.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Overflow` if the number of elements or the size in bytes
+    /// exceeds `isize::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::{DTensor, Error};
+    ///
+    /// assert_eq!(DTensor::<u8, 2>::try_from_elem([2, 3], 0).unwrap().len(), 6);
+    /// assert_eq!(DTensor::<u8, 2>::try_from_elem([1 << 40, 1 << 40], 0), Err(Error::Overflow));
+    /// ```
+    pub fn try_from_elem<I: IntoShape<IntoShape = S>>(shape: I, elem: T) -> Result<Self, Error>
+    where
+        T: Clone,
+    {
+        let shape = shape.into_shape();
+
+        _ = shape.try_len::<T>()?;
+
+        Ok(Self::from_elem(shape, elem))
+    }
+
+    /// Tries to create an array with the results from the given function.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Overflow` if the number of elements or the size in bytes
+    /// exceeds `isize::MAX`.
+    pub fn try_from_fn<I: IntoShape<IntoShape = S>, F>(shape: I, f: F) -> Result<Self, Error>
+    where
+        F: FnMut(&[usize]) -> T,
+    {
+        let shape = shape.into_shape();
+
+        _ = shape.try_len::<T>()?;
+
+        Ok(Self::from_fn(shape, f))
+    }
+
+    /// Tries to create an array with uninitialized elements.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Overflow` if the number of elements or the size in bytes
+    /// exceeds `isize::MAX`.
+    pub fn try_uninit<I: IntoShape<IntoShape = S>>(
+        shape: I,
+    ) -> Result<Tensor<MaybeUninit<T>, S>, Error> {
+        let shape = shape.into_shape();
+
+        _ = shape.try_len::<T>()?;
+
+        Ok(Self::uninit(shape))
+    }
+
+    /// Tries to create an array with elements set to zero.
+    ///
+    /// Zero elements are created using `Default::default()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Overflow` if the number of elements or the size in bytes
+    /// exceeds `isize::MAX`.
+    pub fn try_zeros<I: IntoShape<IntoShape = S>>(shape: I) -> Result<Self, Error>
+    where
+        T: Default,
+    {
+        let shape = shape.into_shape();
+
+        _ = shape.try_len::<T>()?;
+
+        Ok(Self::zeros(shape))
+    }
+
+    /// Creates an array with the reversed shape with elements set to zero.
+    ///
+    /// The array is allocated with the dimensions reversed, so that `col_major` gives
+    /// a view in column-major order with the specified shape. Zero elements are created
+    /// using `Default::default()`.
+    pub fn zeros_transposed<I: IntoShape<IntoShape: Shape<Reverse = S>>>(shape: I) -> Self
+    where
+        T: Default,
+    {
+        Self::zeros(shape.into_shape().reverse())
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl<T> Tensor<T, (Dyn,)> {
+    /// Creates an array with elements from `start` up to but not including `end`,
+    /// separated by the given step.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::{Tensor, view};
+    ///
+    /// assert_eq!(Tensor::arange(0, 10, 3), view![0, 3, 6, 9]);
+    /// assert_eq!(Tensor::arange(1.0, 0.0, -0.25), view![1.0, 0.75, 0.5, 0.25]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the step is zero, or if a value cannot be represented as `f64`.
+    pub fn arange(start: T, end: T, step: T) -> Self
+    where
+        T: Copy + Num + NumCast,
+    {
+        assert!(!step.is_zero(), "step must be non-zero");
+
+        let to_f64 = |x: T| x.to_f64().expect("invalid value");
+        let len = ((to_f64(end) - to_f64(start)) / to_f64(step)).ceil().max(0.0) as usize;
+
+        Self::from_fn([len], |i| start + step * cast(i[0]))
+    }
+
+    /// Creates an array with the given number of evenly spaced elements from `start`
+    /// to `end`, where both endpoints are included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mdarray::{Tensor, view};
+    ///
+    /// assert_eq!(Tensor::linspace(0.0, 1.0, 5), view![0.0, 0.25, 0.5, 0.75, 1.0]);
+    /// ```
+    pub fn linspace(start: T, end: T, len: usize) -> Self
+    where
+        T: Float,
+    {
+        Self::from_fn([len], linspace(start, end, len))
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl<T> Tensor<T, (Dyn, Dyn)> {
+    /// Creates a square array with ones on the diagonal and zeros elsewhere.
+    pub fn eye(n: usize) -> Self
+    where
+        T: One + Zero,
+    {
+        Self::from_fn([n, n], |i| if i[0] == i[1] { T::one() } else { T::zero() })
+    }
+}
+
 #[cfg(not(feature = "nightly"))]
 impl<T, S: Shape, A: Allocator> Tensor<MaybeUninit<T>, S, A> {
     /// Converts the array element type from `MaybeUninit<T>` to `T`.
@@ -1040,3 +1577,17 @@ impl_try_from_array!((X, Y, Z), (A, B, C), [[[T; C]; B]; A]);
 impl_try_from_array!((X, Y, Z, W), (A, B, C, D), [[[[T; D]; C]; B]; A]);
 impl_try_from_array!((X, Y, Z, W, U), (A, B, C, D, E), [[[[[T; E]; D]; C]; B]; A]);
 impl_try_from_array!((X, Y, Z, W, U, V), (A, B, C, D, E, F), [[[[[[T; F]; E]; D]; C]; B]; A]);
+
+#[cfg(feature = "num-traits")]
+fn cast<T: NumCast>(value: usize) -> T {
+    T::from(value).expect("invalid value")
+}
+
+// Returns a function that gives evenly spaced elements, where the last element is
+// set to `end` to avoid rounding errors.
+#[cfg(feature = "num-traits")]
+pub(crate) fn linspace<T: Float>(start: T, end: T, len: usize) -> impl Fn(&[usize]) -> T {
+    let step = if len > 1 { (end - start) / cast(len - 1) } else { T::zero() };
+
+    move |i| if i[0] + 1 == len && len > 1 { end } else { start + step * cast(i[0]) }
+}
@@ -1,18 +1,24 @@
+#[cfg(not(feature = "std"))]
+use crate::prelude::*;
 use core::borrow::{Borrow, BorrowMut};
 use core::fmt::{self, Debug, Formatter};
 use core::hash::{Hash, Hasher};
 use core::marker::PhantomData;
+use core::mem;
 use core::ops::{Deref, DerefMut, Index, IndexMut};
 use core::slice;
 
 use crate::dim::{Const, Dim, Dyn};
+use crate::error::Error;
+#[cfg(feature = "rayon")]
+use crate::expr::ParExpression;
 use crate::expr::{Apply, Expression, IntoExpression, Iter, Map, Zip};
 use crate::index::{self, Axis, DimIndex, Permutation, Resize, SliceIndex, Split, ViewIndex};
-use crate::layout::{Dense, Layout, Strided};
+use crate::layout::{Dense, DenseColMajor, Layout, Strided};
 use crate::mapping::{DenseMapping, Mapping, StridedMapping};
 use crate::raw_slice::RawSlice;
 use crate::shape::{DynRank, IntoShape, Rank, Shape};
-use crate::slice::Slice;
+use crate::slice::{Slice, check_split};
 
 /// Multidimensional array view.
 pub struct View<'a, T, S: Shape = DynRank, L: Layout = Dense> {
@@ -35,6 +41,16 @@ pub type DViewMut<'a, T, const N: usize, L = Dense> = ViewMut<'a, T, Rank<N>, L>
 macro_rules! impl_view {
     ($name:tt, $as_ptr:tt, $from_raw_parts:tt, $raw_mut:tt, {$($mut:tt)?}, $repeatable:tt) => {
         impl<'a, T, S: Shape, L: Layout> $name<'a, T, S, L> {
+            /// Converts the array view into a new array view with the order of elements
+            /// reversed along the specified dimension.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the dimension is out of bounds.
+            pub fn into_flipped<A: Axis>($($mut)? self, axis: A) -> $name<'a, T, S, Strided> {
+                unsafe { Self::flip(self.$as_ptr(), self.mapping(), axis) }
+            }
+
             /// Converts the array view into a new array view indexing the first dimension.
             ///
             /// # Panics
@@ -43,7 +59,7 @@ macro_rules! impl_view {
             pub fn into_at(
                 self,
                 index: usize,
-            ) -> $name<'a, T, S::Tail, L> {
+            ) -> $name<'a, T, S::Tail, L::Inner> {
                 self.into_axis_at(Const::<0>, index)
             }
 
@@ -170,7 +186,7 @@ macro_rules! impl_view {
             /// # Panics
             ///
             /// Panics if the rank is not equal to 2, or if the index is out of bounds.
-            pub fn into_row(self, index: usize) -> $name<'a, T, (<S::Tail as Shape>::Head,), L> {
+            pub fn into_row(self, index: usize) -> $name<'a, T, (<S::Tail as Shape>::Head,), L::Inner> {
                 let shape = self.shape().with_dims(<(S::Head, _)>::from_dims);
 
                 self.into_shape(shape).into_view(index, ..)
@@ -212,7 +228,7 @@ macro_rules! impl_view {
             pub fn into_split_at(
                 self,
                 mid: usize,
-            ) -> ($name<'a, T, Resize<Const<0>, S>, L>, $name<'a, T, Resize<Const<0>, S>, L>) {
+            ) -> ($name<'a, T, Resize<Const<0>, S>, L::Inner>, $name<'a, T, Resize<Const<0>, S>, L::Inner>) {
                 self.into_split_axis_at(Const::<0>, mid)
             }
 
@@ -247,6 +263,80 @@ macro_rules! impl_view {
                 unsafe { $name::new_unchecked(self.$as_ptr(), mapping) }
             }
 
+            /// Tries to convert the array view into a remapped array view.
+            ///
+            /// # Errors
+            ///
+            /// If the shape is not matching static rank or constant-sized dimensions, or if
+            /// the memory layout is not compatible with the new array layout, then an error
+            /// is returned.
+            pub fn try_into_mapping<R: Shape, K: Layout>(
+                $($mut)? self,
+            ) -> Result<$name<'a, T, R, K>, Error> {
+                let mapping = Mapping::try_remap(self.mapping())?;
+
+                Ok(unsafe { $name::new_unchecked(self.$as_ptr(), mapping) })
+            }
+
+            /// Tries to convert the array view into a reshaped array view.
+            ///
+            /// At most one dimension can have dynamic size `usize::MAX`, and is then inferred
+            /// from the other dimensions and the array length.
+            ///
+            /// # Errors
+            ///
+            /// If the array length is changed, if the length overflows, or if the memory
+            /// layout is not compatible, then an error is returned.
+            pub fn try_into_shape<I: IntoShape>(
+                $($mut)? self,
+                shape: I,
+            ) -> Result<$name<'a, T, I::IntoShape, L>, Error> {
+                let mapping = self.mapping().try_reshape(shape.into_shape())?;
+
+                Ok(unsafe { $name::new_unchecked(self.$as_ptr(), mapping) })
+            }
+
+            /// Tries to divide the array view into two at an index along the first dimension.
+            ///
+            /// # Errors
+            ///
+            /// If the rank is not at least 1, or if the split point is larger than the number
+            /// of elements in that dimension, then an error is returned.
+            pub fn try_into_split_at(
+                self,
+                mid: usize,
+            ) -> Result<
+                ($name<'a, T, Resize<Const<0>, S>, L::Inner>, $name<'a, T, Resize<Const<0>, S>, L::Inner>),
+                Error,
+            > {
+                check_split(self.shape(), Const::<0>, mid)?;
+
+                Ok(self.into_split_at(mid))
+            }
+
+            /// Tries to divide the array view into two at an index along the specified
+            /// dimension.
+            ///
+            /// # Errors
+            ///
+            /// If the dimension is out of bounds, or if the split point is larger than the
+            /// number of elements in that dimension, then an error is returned.
+            pub fn try_into_split_axis_at<A: Axis>(
+                self,
+                axis: A,
+                mid: usize,
+            ) -> Result<
+                (
+                    $name<'a, T, Resize<A, S>, Split<A, S, L>>,
+                    $name<'a, T, Resize<A, S>, Split<A, S, L>>,
+                ),
+                Error,
+            > {
+                check_split(self.shape(), axis, mid)?;
+
+                Ok(self.into_split_axis_at(axis, mid))
+            }
+
             /// Creates an array view from a raw pointer and layout.
             ///
             /// # Safety
@@ -286,6 +376,48 @@ macro_rules! impl_view {
                 unsafe { $name::new_unchecked(ptr.offset(count), new_mapping) }
             }
 
+            pub(crate) unsafe fn flip<A: Axis>(
+                ptr: *$raw_mut T,
+                mapping: &L::Mapping<S>,
+                axis: A,
+            ) -> $name<'a, T, S, Strided> {
+                let (offset, strides) = flip_strides(mapping, &[axis.index(mapping.rank())]);
+                let new_mapping = StridedMapping::new(mapping.shape().clone(), strides.as_ref());
+
+                unsafe { $name::new_unchecked(ptr.offset(offset), new_mapping) }
+            }
+
+            pub(crate) unsafe fn rot90(
+                ptr: *$raw_mut T,
+                mapping: &L::Mapping<S>,
+                k: isize,
+                axes: [usize; 2],
+            ) -> $name<'a, T, S::Dyn, Strided> {
+                let [i, j] = axes;
+
+                assert!(i != j && i < mapping.rank() && j < mapping.rank(), "invalid axes");
+
+                // Rotate by flipping one or both dimensions, and swapping for odd turns.
+                let (flip, swap) = match k.rem_euclid(4) {
+                    0 => (&[][..], false),
+                    1 => (&[j][..], true),
+                    2 => (&axes[..], false),
+                    _ => (&[i][..], true),
+                };
+
+                let (offset, mut strides) = flip_strides(mapping, flip);
+                let mut shape = mapping.shape().with_dims(S::Dyn::from_dims);
+
+                if swap {
+                    shape.with_mut_dims(|dims| dims.swap(i, j));
+                    strides.as_mut().swap(i, j);
+                }
+
+                let new_mapping = StridedMapping::new(shape, strides.as_ref());
+
+                unsafe { $name::new_unchecked(ptr.offset(offset), new_mapping) }
+            }
+
             pub(crate) unsafe fn split_axis_at<A: Axis>(
                 ptr: *$raw_mut T,
                 mapping: &L::Mapping<S>,
@@ -318,6 +450,106 @@ macro_rules! impl_view {
             }
         }
 
+        impl<'a, T, S: Shape> $name<'a, T, S, Dense> {
+            /// Converts the array view into an array view in column-major order, where
+            /// the dimensions are reversed.
+            pub fn into_col_major(
+                $($mut)? self
+            ) -> $name<'a, T, S::Reverse, DenseColMajor> {
+                let mapping = Mapping::transpose(self.mapping());
+
+                unsafe { $name::new_unchecked(self.$as_ptr(), mapping) }
+            }
+        }
+
+        impl<'a, T, S: Shape> $name<'a, T, S, DenseColMajor> {
+            /// Converts the array view into an array view in row-major order, where
+            /// the dimensions are reversed.
+            pub fn into_row_major($($mut)? self) -> $name<'a, T, S::Reverse> {
+                let mapping = Mapping::transpose(self.mapping());
+
+                unsafe { $name::new_unchecked(self.$as_ptr(), mapping) }
+            }
+        }
+
+        impl<'a, T, S: Shape> $name<'a, T, S, Strided> {
+            /// Creates an array view from a raw pointer, shape and strides, where the
+            /// strides are given in number of elements.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the number of strides is not matching the rank.
+            ///
+            /// # Safety
+            ///
+            /// The pointer must be non-null and properly aligned, and all elements given
+            /// by the shape and strides must be valid for the lifetime of the view with
+            /// the same aliasing rules as for references.
+            pub unsafe fn from_raw_parts(ptr: *$raw_mut T, shape: S, strides: &[isize]) -> Self {
+                unsafe { $name::new_unchecked(ptr, StridedMapping::new(shape, strides)) }
+            }
+
+            /// Tries to create an array view from a raw pointer, shape and strides, where
+            /// the strides are given in number of elements.
+            ///
+            /// # Errors
+            ///
+            /// If the pointer is null or not aligned, if the number of strides is not matching
+            /// the rank, if the memory extent overflows `isize`, or if elements are overlapping
+            /// in a mutable array view, then an error is returned.
+            ///
+            /// # Safety
+            ///
+            /// All elements given by the shape and strides must be valid for the lifetime of
+            /// the view with the same aliasing rules as for references.
+            pub unsafe fn try_from_raw_parts(
+                ptr: *$raw_mut T,
+                shape: S,
+                strides: &[isize],
+            ) -> Result<Self, Error> {
+                check_raw_parts(ptr as *const T, &shape, strides, !$repeatable)?;
+
+                Ok(unsafe { $name::from_raw_parts(ptr, shape, strides) })
+            }
+
+            /// Tries to create an array view from a raw pointer, shape and strides, where
+            /// the strides are given in bytes.
+            ///
+            /// # Errors
+            ///
+            /// If the strides are not a multiple of the element size, or if any of the
+            /// conditions for `try_from_raw_parts` is not met, then an error is returned.
+            ///
+            /// # Safety
+            ///
+            /// All elements given by the shape and strides must be valid for the lifetime of
+            /// the view with the same aliasing rules as for references.
+            pub unsafe fn try_from_raw_parts_bytes(
+                ptr: *$raw_mut T,
+                shape: S,
+                byte_strides: &[isize],
+            ) -> Result<Self, Error> {
+                let size = mem::size_of::<T>() as isize;
+                let mut strides = shape.new_dims::<isize>();
+
+                if byte_strides.len() != shape.rank() {
+                    return Err(Error::RankMismatch);
+                }
+
+                for (stride, &byte_stride) in strides.as_mut().iter_mut().zip(byte_strides) {
+                    if size > 0 {
+                        if byte_stride % size != 0 {
+                            return Err(Error::InvalidStrides);
+                        }
+
+                        *stride = byte_stride / size;
+                    }
+                }
+
+                unsafe { $name::try_from_raw_parts(ptr, shape, strides.as_ref()) }
+            }
+        }
+
         impl<'a, T, U, S: Shape, L: Layout> Apply<U> for &'a $name<'_, T, S, L> {
             type Output<F: FnMut(&'a T) -> U> = Map<Self::IntoExpr, F>;
             type ZippedWith<I: IntoExpression, F: FnMut((&'a T, I::Item)) -> U> =
@@ -476,6 +708,112 @@ macro_rules! impl_view {
 impl_view!(View, as_ptr, from_raw_parts, const, {}, true);
 impl_view!(ViewMut, as_mut_ptr, from_raw_parts_mut, mut, {mut}, false);
 
+impl<'a, T, S: Shape, L: Layout> View<'a, T, S, L> {
+    /// Converts the array view into a new array view that is broadcast to the specified shape.
+    ///
+    /// Dimensions are matched from the end. Added outer dimensions and dimensions of
+    /// size one are repeated by having zero stride. There is no mutable counterpart,
+    /// since the resulting array view may alias elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array view cannot be broadcast to the shape.
+    pub fn into_broadcast<I: IntoShape>(self, shape: I) -> View<'a, T, I::IntoShape, Strided> {
+        let shape = shape.into_shape();
+        let mut strides = shape.new_dims::<isize>();
+
+        assert!(self.rank() <= shape.rank(), "incompatible shapes");
+
+        let offset = shape.rank() - self.rank();
+
+        shape.with_dims(|dims| {
+            for i in 0..self.rank() {
+                if self.dim(i) == dims[offset + i] {
+                    strides.as_mut()[offset + i] = self.stride(i);
+                } else {
+                    assert!(self.dim(i) == 1, "incompatible shapes");
+                }
+            }
+        });
+
+        let mapping = StridedMapping::new(shape, strides.as_ref());
+
+        unsafe { View::new_unchecked(self.as_ptr(), mapping) }
+    }
+}
+
+// Returns the pointer offset and strides for reversing the order of elements along
+// the given dimensions, where the pointer is not offset if the array is empty.
+fn flip_strides<M: Mapping>(
+    mapping: &M,
+    axes: &[usize],
+) -> (isize, <M::Shape as Shape>::Dims<isize>) {
+    let mut offset = 0;
+    let mut strides = mapping.shape().new_dims::<isize>();
+
+    for i in 0..mapping.rank() {
+        strides.as_mut()[i] = mapping.stride(i);
+    }
+
+    for &i in axes {
+        if !mapping.is_empty() {
+            offset += (mapping.dim(i) as isize - 1) * strides.as_ref()[i];
+        }
+
+        strides.as_mut()[i] = -strides.as_ref()[i];
+    }
+
+    (offset, strides)
+}
+
+// Checks that the pointer and strides are valid for an array view, where elements
+// must not overlap if the array view is mutable.
+fn check_raw_parts<T, S: Shape>(
+    ptr: *const T,
+    shape: &S,
+    strides: &[isize],
+    unique: bool,
+) -> Result<(), Error> {
+    if ptr.is_null() || !ptr.is_aligned() {
+        return Err(Error::InvalidPointer);
+    }
+
+    if strides.len() != shape.rank() {
+        return Err(Error::RankMismatch);
+    }
+
+    if shape.checked_len().ok_or(Error::Overflow)? == 0 || mem::size_of::<T>() == 0 {
+        return Ok(());
+    }
+
+    let mut pairs = shape.with_dims(|dims| {
+        let pairs = dims.iter().zip(strides).map(|(&size, stride)| (size, stride.unsigned_abs()));
+
+        pairs.filter(|&(size, _)| size > 1).collect::<Vec<_>>()
+    });
+
+    pairs.sort_by_key(|&(_, stride)| stride);
+
+    // Sorted by stride, the elements are unique if each stride is larger than the
+    // largest offset reachable with the previous dimensions.
+    let mut extent = 0usize;
+
+    for (size, stride) in pairs {
+        if unique && stride <= extent {
+            return Err(Error::InvalidStrides);
+        }
+
+        let offset = (size - 1).checked_mul(stride);
+
+        extent = offset.and_then(|x| x.checked_add(extent)).ok_or(Error::Overflow)?;
+    }
+
+    match extent.checked_mul(mem::size_of::<T>()) {
+        Some(bytes) if bytes <= isize::MAX as usize => Ok(()),
+        _ => Err(Error::Overflow),
+    }
+}
+
 macro_rules! impl_into_view {
     ($n:tt, ($($xyz:tt),+), ($($abc:tt),+), ($($idx:tt),+)) => {
         impl<'a, T, $($xyz: Dim,)+ L: Layout> View<'a, T, ($($xyz,)+), L> {
@@ -579,6 +917,44 @@ impl<T, S: Shape, L: Layout> Clone for View<'_, T, S, L> {
 
 impl<T, S: Shape, L: Layout<Mapping<S>: Copy>> Copy for View<'_, T, S, L> {}
 
+#[cfg(feature = "rayon")]
+impl<'a, T: Sync, S: Shape, L: Layout> ParExpression for View<'a, T, S, L> {
+    type Part = View<'a, T, S::Dyn, L::Inner>;
+
+    fn clone_part(&self) -> Self::Part {
+        self.clone().into_part()
+    }
+
+    fn into_part(self) -> Self::Part {
+        self.into_mapping()
+    }
+
+    fn split_part(self, mid: usize) -> (Self::Part, Self::Part) {
+        let (first, second) = self.into_split_axis_at(Const::<0>, mid);
+
+        (first.into_mapping(), second.into_mapping())
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, T: Send, S: Shape, L: Layout> ParExpression for ViewMut<'a, T, S, L> {
+    type Part = ViewMut<'a, T, S::Dyn, L::Inner>;
+
+    fn clone_part(&self) -> Self::Part {
+        panic!("expression not repeatable");
+    }
+
+    fn into_part(self) -> Self::Part {
+        self.into_mapping()
+    }
+
+    fn split_part(self, mid: usize) -> (Self::Part, Self::Part) {
+        let (first, second) = self.into_split_axis_at(Const::<0>, mid);
+
+        (first.into_mapping(), second.into_mapping())
+    }
+}
+
 impl<T, S: Shape, L: Layout> DerefMut for ViewMut<'_, T, S, L> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         self.slice.as_mut_slice()
@@ -19,15 +19,19 @@ use std::hash::{Hash, Hasher};
 use std::ops::RangeFull;
 
 #[cfg(feature = "serde")]
-use serde_test::{Token, assert_tokens};
+use serde_test::{Token, assert_de_tokens, assert_de_tokens_error, assert_tokens};
 
 #[cfg(feature = "nightly")]
 use aligned_alloc::AlignedAlloc;
 use mdarray::expr::{self, Apply, Expression, IntoExpression};
+use mdarray::format::PrintOptions;
 use mdarray::index::{Axis, Cols, Rows};
-use mdarray::{Array, DTensor, DView, DViewMut, Tensor, View, ViewMut, array, tensor, view};
-use mdarray::{Const, Dense, Dyn, DynRank, Layout, Rank, Shape, StepRange, Strided, step};
-use mdarray::{DenseMapping, IntoCloned, Mapping, StridedMapping};
+use mdarray::{
+    Array, DSlice, DTensor, DView, DViewMut, Error, Tensor, View, ViewMut, array, concat, stack,
+    tensor, view,
+};
+use mdarray::{Const, Dense, DenseColMajor, Dyn, DynRank, Layout, Rank, Shape, StepRange, Strided};
+use mdarray::{DenseColMajorMapping, DenseMapping, IntoCloned, Mapping, StridedMapping, step};
 
 type U0 = Const<0>;
 type U1 = Const<1>;
@@ -78,6 +82,208 @@ fn sr() -> StepRange<RangeFull, isize> {
     step(.., 2)
 }
 
+#[cfg(feature = "approx")]
+#[test]
+fn test_approx() {
+    use approx::{AbsDiffEq, assert_abs_diff_eq, assert_relative_eq, assert_ulps_eq};
+    use mdarray::{assert_array_abs_diff_eq, assert_array_relative_eq, assert_array_ulps_eq};
+
+    let a = tensor![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+    let b = a.clone().map(|x| x + 1e-10);
+
+    assert_abs_diff_eq!(a, b, epsilon = 1e-9);
+    assert_abs_diff_eq!(a.view(.., 1..), b.view(.., 1..), epsilon = 1e-9);
+    assert_relative_eq!(a.transpose(), b.transpose().to_tensor(), max_relative = 1e-9);
+    assert_ulps_eq!(a[..], a.clone().map(|x| x + 0.0)[..]);
+    assert_relative_eq!(
+        Array::<f64, (Const<2>,)>::from([1.0, 2.0]),
+        view![1.0, 2.0 + 1e-15],
+        max_relative = 1e-12
+    );
+
+    assert!(a.abs_diff_ne(&b, 1e-11));
+    assert!(a.abs_diff_ne(&a.view(..1, ..), 1.0));
+    assert!(a.abs_diff_ne(&a.reshape([3, 2]), 1.0));
+
+    let c = b.view(.., step(.., -1));
+    let mut d = b.clone();
+
+    d[[1, 1]] = 5.5;
+
+    assert_eq!(a.first_abs_diff_ne(&b, 1e-9), None);
+    assert_eq!(a.first_abs_diff_ne(&d, 0.1), Some([1, 1]));
+    assert_eq!(a.first_abs_diff_ne(&c, 1.5), Some([0, 0]));
+    assert_eq!(a.view(1.., ..).first_relative_ne(&c.view(1.., ..), 0.0, 0.5), None);
+    assert_eq!(a.view(1.., ..).first_relative_ne(&c.view(1.., ..), 0.0, 0.3), Some([0, 0]));
+    assert_eq!(a.first_ulps_ne(&a.transpose().transpose(), 0.0, 0), None);
+    assert_eq!(a.reshape([6]).first_ulps_ne(&b.reshape([6]), 0.0, 4), Some([0]));
+
+    assert_array_abs_diff_eq!(a, b, epsilon = 1e-9);
+    assert_array_abs_diff_eq!(*a, a.transpose().transpose());
+    assert_array_relative_eq!(a.view(1.., ..), c.view(1.., ..), epsilon = 0.0, max_relative = 0.5);
+    assert_array_ulps_eq!(a.reshape([6]), b.reshape([6]), max_ulps = 1 << 20);
+
+    let result = std::panic::catch_unwind(|| assert_array_abs_diff_eq!(a, d, epsilon = 0.1));
+    let msg = *result.unwrap_err().downcast::<String>().unwrap();
+
+    assert_eq!(
+        msg,
+        "assertion `left.abs_diff_eq(right)` failed at index [1, 1]\n  left: 5.0\n right: 5.5"
+    );
+
+    let result = std::panic::catch_unwind(|| assert_array_relative_eq!(a, a.reshape([3, 2])));
+    let msg = *result.unwrap_err().downcast::<String>().unwrap();
+
+    assert_eq!(
+        msg,
+        "assertion `left.relative_eq(right)` failed: shape mismatch\n  left: [2, 3]\n right: [3, 2]"
+    );
+}
+
+#[cfg(feature = "arrow")]
+#[test]
+fn test_arrow() {
+    use arrow_array::types::{Float32Type, Float64Type, Int64Type};
+    use arrow_array::{Array, FixedSizeListArray, Float64Array, Int64Array};
+
+    let t = tensor![1.0, 2.0, 3.0];
+    let ptr = t.as_ptr();
+
+    let a = Float64Array::from(t);
+
+    assert_eq!(a.values().as_ptr(), ptr);
+    assert_eq!(a.values(), &[1.0, 2.0, 3.0]);
+
+    let u = Tensor::from(a);
+
+    assert_eq!(u.as_ptr(), ptr);
+    assert_eq!(u, view![1.0, 2.0, 3.0]);
+
+    let a = Int64Array::from(vec![1, 2, 3, 4]).slice(1, 2);
+
+    assert_eq!(Tensor::from(a), view![2, 3]);
+
+    let a = Int64Array::from(vec![Some(1), None, Some(3)]);
+
+    assert_eq!(a.null_count(), 1);
+    assert_eq!(Tensor::from(a), view![1, 0, 3]);
+
+    let t = Tensor::from_fn([3, 2], |i| (2 * i[0] + i[1]) as f64);
+    let ptr = t.as_ptr();
+
+    let a = t.into_fixed_size_list::<Float64Type>();
+
+    assert_eq!((a.len(), a.value_length()), (3, 2));
+    assert_eq!(a.value(1).as_any().downcast_ref::<Float64Array>().unwrap().values(), &[2.0, 3.0]);
+
+    let b = a.clone();
+    let u = DTensor::<f64, 2>::try_from_fixed_size_list::<Float64Type>(a).unwrap();
+
+    assert_ne!(u.as_ptr(), ptr);
+    assert_eq!(u, view![[0.0, 1.0], [2.0, 3.0], [4.0, 5.0]]);
+
+    assert_eq!(
+        DTensor::<f32, 2>::try_from_fixed_size_list::<Float32Type>(b.clone()),
+        Err(Error::Unsupported)
+    );
+
+    let c = DTensor::<f64, 2>::try_from_fixed_size_list::<Float64Type>(b).unwrap();
+
+    assert_eq!(c.as_ptr(), ptr);
+
+    let e = DTensor::<i64, 2>::from_elem([0, 4], 0).into_fixed_size_list::<Int64Type>();
+
+    assert_eq!(FixedSizeListArray::len(&e), 0);
+    assert_eq!(
+        DTensor::<i64, 2>::try_from_fixed_size_list::<Int64Type>(e).unwrap().shape(),
+        &(0, 4)
+    );
+}
+
+#[cfg(feature = "num-traits")]
+#[test]
+fn test_backend() {
+    use mdarray::backend::{Backend, Reference};
+
+    let a = Tensor::from_fn([3, 4], |i| (4 * i[0] + i[1]) as f64);
+    let b = Tensor::from_fn([4, 5], |i| (i[0] + 2 * i[1]) as f64 - 3.0);
+    let ab = a.matmul(&b);
+
+    let mut c = DTensor::<f64, 2>::from_elem([3, 5], f64::NAN);
+
+    Reference.gemm(1.0, &a, &b, 0.0, &mut c);
+
+    assert_eq!(c, ab);
+
+    let mut c = DTensor::<f64, 2>::from_elem([5, 3], 1.0);
+
+    Reference.gemm(2.0, &b.transpose(), &a.transpose(), 1.0, &mut c);
+
+    assert_eq!(c, ab.transpose().map(|x| 2.0 * x + 1.0).eval());
+
+    let mut c = DTensor::<f64, 2>::from_elem([5, 3], 0.0);
+
+    Reference.gemm(1.0, &a, &b, 0.0, &mut c.transpose_mut());
+
+    assert_eq!(c.transpose(), ab);
+
+    let mut c = DTensor::<f64, 2>::from_elem([6, 10], 0.0);
+    let r = b.view(.., step(.., -1));
+
+    Reference.gemm(1.0, &a.view(step(.., -1), ..), &r, 0.0, &mut c.view_mut(1..4, step(.., 2)));
+
+    assert_eq!(c.view(1..4, step(.., 2)), a.view(step(.., -1), ..).matmul(&r));
+    assert_eq!(c.view(.., step(1.., 2)), DTensor::<f64, 2>::from_elem([6, 5], 0.0));
+
+    let x = tensor![1.0, 2.0, 3.0];
+    let mut y = tensor![1.0, 1.0, 1.0, 1.0];
+
+    Reference.gemv(1.0, &a.transpose(), &x, -1.0, &mut y.view_mut(step(.., -1)));
+
+    assert_eq!(y, view![49.0, 43.0, 37.0, 31.0]);
+    assert_eq!(Reference.dot(&x, &y.view(..3)), 246.0);
+
+    Reference.axpy(2.0, &x.view(step(.., -1)), &mut y.view_mut(1..));
+
+    assert_eq!(y, view![49.0, 49.0, 41.0, 33.0]);
+
+    let z = tensor![2.0];
+
+    assert_eq!(Reference.dot(&z.broadcast_to([3]), &x), 12.0);
+
+    let w = tensor![1.0, 10.0, 100.0];
+    let mut v = tensor![0.0, 0.0, 0.0];
+
+    // The pointer for a negative increment is to the element with the lowest address.
+    assert_eq!(unsafe { Reference.dot_raw(3, x.as_ptr(), -1, w.as_ptr(), 1) }, 123.0);
+
+    unsafe { Reference.axpy_raw(3, 1.0, w.as_ptr(), 1, v.as_mut_ptr(), -1) };
+
+    assert_eq!(v, view![100.0, 10.0, 1.0]);
+
+    let mut t =
+        tensor![[2.0, 1.0, 1.0], [4.0, -6.0, 0.0], [-2.0, 7.0, 2.0]].transpose().to_tensor();
+    let mut ipiv = [0; 3];
+
+    assert_eq!(Reference.getrf(&mut t.transpose_mut(), &mut ipiv), Ok(()));
+    assert_eq!(ipiv, [1, 1, 2]);
+    assert_eq!(t.transpose(), view![[4.0, -6.0, 0.0], [0.5, 4.0, 1.0], [-0.5, 1.0, 1.0]]);
+
+    let mut t = tensor![[1.0, 2.0], [2.0, 4.0]];
+
+    assert_eq!(Reference.getrf(&mut t, &mut [0; 2]), Err(Error::Singular));
+
+    let mut t = tensor![[4.0, 2.0], [2.0, 5.0]];
+
+    assert_eq!(Reference.potrf(&mut t), Ok(()));
+    assert_eq!(t, view![[2.0, 2.0], [1.0, 2.0]]);
+    assert_eq!(
+        Reference.potrf(&mut tensor![[1.0, 2.0], [2.0, 1.0]]),
+        Err(Error::NotPositiveDefinite)
+    );
+}
+
+#[allow(clippy::bool_assert_comparison, clippy::iter_skip_next)]
 #[test]
 fn test_base() {
     let mut a = DTensor::<usize, 3>::default();
@@ -363,6 +569,363 @@ fn test_base() {
     assert_eq!(u.as_ptr() as usize % 64, 0);
 }
 
+#[test]
+fn test_broadcast() {
+    let a = DTensor::<i32, 2>::from([[1], [2], [3]]);
+    let b = DTensor::<i32, 2>::from([[10, 20]]);
+
+    assert_eq!((&a + &b).eval(), view![[11, 21], [12, 22], [13, 23]]);
+    assert_eq!((&b + &a).eval(), view![[11, 21], [12, 22], [13, 23]]);
+
+    let c = DTensor::<i32, 2>::from([[1, 2], [3, 4], [5, 6]]);
+
+    assert_eq!((&c * &a).eval(), view![[1, 2], [6, 8], [15, 18]]);
+    assert_eq!(c.clone() - &b, view![[-9, -18], [-7, -16], [-5, -14]]);
+    assert_eq!((c.view(.., step(.., -1)) + &a).eval(), view![[3, 2], [6, 5], [9, 8]]);
+
+    let d = expr::from_fn([3, 1, 2], |i| 10 * i[0] + i[2]);
+    let e = expr::from_fn([4, 1], |i| 100 * i[0]);
+
+    let f = expr::zip(d, e).map(|(x, y)| x + y).eval();
+
+    assert_eq!(f.shape(), &(3, 4, 2));
+    assert_eq!(f[[2, 3, 1]], 321);
+    assert_eq!(f.view(1, .., 0), view![10, 110, 210, 310]);
+
+    let mut g = DTensor::<i32, 3>::zeros([2, 2, 3]);
+
+    g.assign(&b.view(.., ..1));
+    g.view_mut(1, .., ..).assign(view![1, 2, 3]);
+
+    assert_eq!(g, view![[[10, 10, 10], [10, 10, 10]], [[1, 2, 3], [1, 2, 3]]]);
+
+    let mut h = DTensor::<i32, 2>::from([[1, 2]]);
+
+    h.expand(&b.view(.., ..1));
+    h.expand(DTensor::<i32, 2>::from([[3], [4]]).expr());
+    h.expand(&view![5, 6]);
+
+    assert_eq!(h, view![[1, 2], [10, 10], [3, 3], [4, 4], [5, 6]]);
+
+    let x = array![[1, 2, 3]];
+    let y = tensor![[1, 2, 3], [4, 5, 6]];
+
+    assert_eq!((x.reshape([1, 3]) + &y).eval(), view![[2, 4, 6], [5, 7, 9]]);
+    assert_eq!(y + x.reshape([1, 3]), view![[2, 4, 6], [5, 7, 9]]);
+    assert_eq!(expr::zip(&x, &tensor![[4, 5, 6]]).map(|(a, b)| a + b).eval(), view![[5, 7, 9]]);
+
+    let v = a.broadcast_to([2, 3, 2]);
+
+    assert_eq!(v.shape(), &(2, 3, 2));
+    assert_eq!(v.strides(), &[0, 1, 0]);
+    assert_eq!(v, view![[[1, 1], [2, 2], [3, 3]], [[1, 1], [2, 2], [3, 3]]]);
+
+    let w = b.view(0, ..).into_broadcast((Const::<2>, 2));
+
+    assert_eq!(w.strides(), &[0, 1]);
+    assert_eq!(w.to_tensor() + c.view(..2, ..), view![[11, 22], [13, 24]]);
+    assert_eq!(c.broadcast_to([2, 3, 2]).view(1, .., ..), c);
+}
+
+#[test]
+fn test_col_major() {
+    let a = Tensor::from_fn_transposed([2, 3], |i| 10 * i[0] + i[1]);
+    let v: View<usize, (usize, usize), DenseColMajor> = a.col_major();
+
+    assert_eq!(a.shape(), &(3, 2));
+    assert_eq!(a[..], [0, 10, 1, 11, 2, 12]);
+    assert_eq!(v, view![[0, 1, 2], [10, 11, 12]]);
+    assert_eq!(v.iter().copied().collect::<Vec<_>>(), [0, 1, 2, 10, 11, 12]);
+    assert_eq!(v.to_tensor()[..], [0, 1, 2, 10, 11, 12]);
+
+    assert_eq!((v.stride(0), v.stride(1)), (1, 2));
+    assert_eq!(v.mapping().linear_offset(4), 3);
+    assert!(!v.is_contiguous() && a.view(..1, ..).col_major().is_contiguous());
+    assert_eq!(a.transpose().strides(), &[1, 2]);
+
+    let w: View<usize, (usize, usize), Dense> = v.row_major();
+
+    assert_eq!(w.as_ptr(), a.as_ptr());
+    assert_eq!(w, a);
+
+    let x: View<usize, (usize,), Strided> = v.at(1);
+
+    assert_eq!(x, view![10, 11, 12]);
+    assert_eq!(v.view(.., 2), view![2, 12]);
+    assert_eq!(v.col(1), view![1, 11]);
+    assert_eq!(v.row(0), view![0, 1, 2]);
+    assert_eq!(v.cols().into_iter().map(|x| x[1]).collect::<Vec<_>>(), [10, 11, 12]);
+    assert_eq!(v.split_at(1).1, view![[10, 11, 12]]);
+
+    let y = v.reshape([2, 1, 3]);
+
+    assert_eq!(y.mapping(), &DenseColMajorMapping::new((2, 1, 3)));
+    assert_eq!(y, view![[[0, 1, 2]], [[10, 11, 12]]].into_dyn().into_shape([2, 1, 3]));
+    assert_eq!(w.view(.., ..).transpose().remap::<(usize, usize), DenseColMajor>(), v);
+
+    let b = tensor![[1, 2], [3, 4], [5, 6]];
+
+    assert_eq!(v.matmul(&b), view![[13, 16], [103, 136]]);
+    assert_eq!(b.transpose().matmul(&b), view![[35, 44], [44, 56]]);
+
+    let mut c = Tensor::<i32, (Dyn, Dyn, Dyn)>::zeros_transposed([2, 3, 4]);
+
+    c.col_major_mut()[[1, 2, 3]] = 1;
+
+    assert_eq!(c.shape(), &(4, 3, 2));
+    assert_eq!(c[[3, 2, 1]], 1);
+    assert_eq!(Tensor::from_elem_transposed([2, 3], 1.0).col_major(), view![[1.0; 3]; 2]);
+    assert_eq!(c.col_major_mut().into_row_major().as_ptr(), c.as_ptr());
+    assert_eq!(c.view(.., .., ..).into_col_major().into_row_major(), c);
+}
+
+#[test]
+fn test_compare() {
+    let a = tensor![[1, 2, 3], [4, 5, 6]];
+    let b = tensor![3, 2, 1];
+
+    assert_eq!(a.expr().eq_elem(&b).eval(), view![[false, true, false], [false, false, false]]);
+    assert_eq!(a.expr().ne_elem(&b).eval(), view![[true, false, true], [true, true, true]]);
+    assert_eq!(a.expr().lt(&b).eval(), view![[true, false, false], [false, false, false]]);
+    assert_eq!(a.expr().le(&b).eval(), view![[true, true, false], [false, false, false]]);
+    assert_eq!(a.expr().gt(&b).eval(), view![[false, false, true], [true, true, true]]);
+    assert_eq!(a.expr().ge(&b).eval(), view![[false, true, true], [true, true, true]]);
+
+    assert_eq!(a.expr().gt(expr::fill(&2)).count_true(), 4);
+    assert!(a.view(.., 1).ge(expr::fill(&2)).all());
+    assert!(!a.expr().copied().lt(expr::fill(0)).any());
+
+    let mask = a.expr().ge(expr::fill(&3)).eval();
+
+    assert!(mask.expr().any());
+    assert!(!mask.expr().all());
+    assert_eq!(mask.expr().count_true(), 4);
+    assert!(Tensor::<bool, _>::from_elem([0, 3], false).expr().all());
+    assert!(!Tensor::<bool, _>::from_elem([0, 3], true).expr().any());
+
+    let c = expr::where_(&mask, &a, expr::fill(&0)).copied().eval();
+
+    assert_eq!(c, view![[0, 0, 3], [4, 5, 6]]);
+
+    // Clip to the range 2..=5 with broadcasting of the bounds.
+    let lo = expr::where_(a.expr().lt(expr::fill(&2)), expr::fill(&2), &a);
+    let d = tensor![5];
+    let e = lo.copied().eval();
+    let f = expr::where_(e.expr().gt(&d), &d, &e).copied().eval();
+
+    assert_eq!(f, view![[2, 2, 3], [4, 5, 5]]);
+}
+
+#[cfg(feature = "num-complex")]
+#[test]
+fn test_complex() {
+    use num_complex::Complex;
+
+    let mut t = Tensor::from_fn([2, 3], |i| Complex::new(i[0] as f64, i[1] as f64));
+
+    assert_eq!(t.re(), view![[0.0, 0.0, 0.0], [1.0, 1.0, 1.0]]);
+    assert_eq!(t.im(), view![[0.0, 1.0, 2.0], [0.0, 1.0, 2.0]]);
+    assert_eq!(t.re().strides(), &[6, 2]);
+
+    assert_eq!(t.view(.., step(.., -2)).im(), view![[2.0, 0.0], [2.0, 0.0]]);
+    assert_eq!(t.transpose().re(), view![[0.0, 1.0], [0.0, 1.0], [0.0, 1.0]]);
+
+    t.re_mut().assign(&view![5.0, 6.0, 7.0]);
+    t.im_mut()[[1, 2]] = -1.0;
+
+    assert_eq!(t[[0, 1]], Complex::new(6.0, 1.0));
+    assert_eq!(t[[1, 2]], Complex::new(7.0, -1.0));
+
+    let c = t.conj_transpose().eval();
+
+    assert_eq!(c.shape(), &(3, 2));
+    assert_eq!(c[[2, 1]], Complex::new(7.0, 1.0));
+    assert_eq!(t.conj().eval()[[0, 2]], Complex::new(7.0, -2.0));
+
+    let e = Tensor::<Complex<f64>, (Dyn,)>::from_elem([0], Complex::new(0.0, 0.0));
+
+    assert!(e.re().is_empty() && e.im().is_empty());
+}
+
+#[test]
+fn test_concat() {
+    let a = tensor![[1, 2, 3], [4, 5, 6]];
+    let b = tensor![[7, 8, 9]];
+    let c = tensor![[10], [11]];
+
+    let t = concat(Const::<0>, &[&a, &b]);
+
+    assert_eq!(t, view![[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    assert_eq!(
+        concat(Rows, &[a.view(.., 1..), c.view(.., 0..1), a.view(.., ..3)]).shape(),
+        &(2, 6)
+    );
+    assert_eq!(concat(Rows, &[&a, &c]), view![[1, 2, 3, 10], [4, 5, 6, 11]]);
+    assert_eq!(
+        concat(Cols, &[&a, &a.view(1.., ..).to_tensor()]),
+        view![[1, 2, 3], [4, 5, 6], [4, 5, 6]]
+    );
+    assert_eq!(concat(1, &[a.view(.., ..0), c.view(.., 0..1)]), c.into_dyn());
+
+    let x = Tensor::from_fn([2, 3, 4], |i| 100 * i[0] + 10 * i[1] + i[2]);
+    let y = Tensor::from_fn([2, 1, 4], |i| 1000 + 100 * i[0] + i[2]);
+
+    let z: Tensor<usize, (usize, usize, usize)> = concat(Const::<1>, &[x.expr(), y.expr()]);
+
+    assert_eq!(z.shape(), &(2, 4, 4));
+    assert_eq!(z.view(.., ..3, ..), x);
+    assert_eq!(z.view(.., 3..4, ..), y);
+
+    let u = array![1, 2, 3];
+    let v = array![4, 5, 6];
+
+    let w: Array<i32, (Const<2>, Const<3>)> = stack(Const::<0>, &[&u, &v]);
+
+    assert_eq!(w, view![[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(stack(Rows, &[&u, &v, &u]), view![[1, 4, 1], [2, 5, 2], [3, 6, 3]]);
+
+    let s = stack(Const::<1>, &[x.expr(), x.expr()]);
+
+    assert_eq!(s.shape(), &(2, Const::<2>, 3, 4));
+    assert_eq!(s.view(.., 0, .., ..), x);
+    assert_eq!(s.view(.., 1, .., ..), x);
+    assert_eq!(stack(2, &[x.expr()]).into_shape([2, 3, 4]), x);
+
+    let mut t = t.into_dyn();
+
+    t.append_axis(Rows, view![[0], [0], [0]]);
+    t.append_axis(0, tensor![[1, 2, 3, 4]]);
+
+    assert_eq!(t, view![[1, 2, 3, 0], [4, 5, 6, 0], [7, 8, 9, 0], [1, 2, 3, 4]].into_dyn());
+
+    let mut x = x;
+
+    x.append_axis(Const::<1>, &y);
+    x.append_axis(Rows, Tensor::from_elem([2, 4, 1], 9));
+
+    assert_eq!(x.shape(), &(2, 4, 5));
+    assert_eq!(x.view(.., .., ..4), z);
+    assert_eq!(x.view(.., .., 4), view![[9; 4]; 2]);
+
+    let mut e = Tensor::<i32, DynRank>::from_elem(&[0, 3][..], 0);
+
+    e.append_axis(1, tensor![[1, 2], [3, 4]]);
+    e.append_axis(0, tensor![[5, 6]]);
+
+    assert_eq!(e, view![[1, 2], [3, 4], [5, 6]].into_dyn());
+}
+
+#[cfg(feature = "dlpack")]
+#[test]
+fn test_dlpack() {
+    use mdarray::dlpack::{self, DLDataType, ManagedTensor};
+
+    let ptr = dlpack::into_dlpack(Tensor::from_fn([2, 3], |i| (3 * i[0] + i[1]) as i32));
+
+    unsafe {
+        let dl_tensor = &(*ptr).dl_tensor;
+
+        assert_eq!(dl_tensor.ndim, 2);
+        assert_eq!(dl_tensor.dtype, DLDataType { code: DLDataType::INT, bits: 32, lanes: 1 });
+        assert_eq!(*dl_tensor.shape.add(1), 3);
+        assert_eq!(*dl_tensor.strides, 3);
+
+        assert_eq!(ManagedTensor::<f32>::from_raw(ptr).unwrap_err(), Error::Unsupported);
+        assert_eq!(ManagedTensor::<i32, (Dyn,)>::from_raw(ptr).unwrap_err(), Error::RankMismatch);
+
+        // Reinterpret the array as transposed, and check that the deleter is called once.
+        (*ptr).dl_tensor.shape.swap((*ptr).dl_tensor.shape.add(1));
+        (*ptr).dl_tensor.strides.swap((*ptr).dl_tensor.strides.add(1));
+
+        let mut t = ManagedTensor::<i32, (Dyn, U2)>::from_raw(ptr).unwrap();
+
+        t[[2, 1]] = 9;
+
+        assert_eq!(*t, view![[0, 3], [1, 4], [2, 9]]);
+
+        let ptr = t.into_raw();
+
+        (*ptr).dl_tensor.strides = std::ptr::null_mut();
+        (*ptr).dl_tensor.byte_offset = 4;
+        *(*ptr).dl_tensor.shape = 1;
+
+        let t = ManagedTensor::<i32>::from_raw(ptr).unwrap();
+
+        assert_eq!(*t, view![[1, 2]].into_dyn());
+    }
+
+    assert!(unsafe { ManagedTensor::<i32>::from_raw(std::ptr::null_mut()) }.is_err());
+}
+
+#[test]
+fn test_dyn_rank() {
+    let s = Tensor::from_fn([2, 1, 3], |i| 10 * i[0] + i[2]);
+    let mut t = s.clone().into_dyn();
+    let v = t.expr();
+
+    assert_eq!(v.insert_axis(3).unwrap().dims(), [2, 1, 3, 1]);
+    assert_eq!(v.insert_axis(0).unwrap(), v.reshape([1, 2, 1, 3]));
+    assert_eq!(v.insert_axis(4), Err(Error::IndexOutOfBounds));
+    assert_eq!(v.remove_axis(1).unwrap(), view![[0, 1, 2], [10, 11, 12]].into_dyn());
+    assert_eq!(v.remove_axis(0), Err(Error::ShapeMismatch));
+    assert_eq!(v.remove_axis(3), Err(Error::IndexOutOfBounds));
+    assert_eq!(v.squeeze().dims(), [2, 3]);
+
+    assert_eq!(v.permute_dyn(&[2, 0, 1]).unwrap().dims(), [3, 2, 1]);
+    assert_eq!(v.permute_dyn(&[2, 0, 1]).unwrap().squeeze(), view![[0, 10], [1, 11], [2, 12]]);
+    assert_eq!(v.permute_dyn(&[0, 1]), Err(Error::RankMismatch));
+    assert_eq!(v.permute_dyn(&[0, 1, 3]), Err(Error::IndexOutOfBounds));
+    assert_eq!(v.permute_dyn(&[0, 1, 1]), Err(Error::InvalidPermutation));
+    assert_eq!(v.swap_axes(0, 2).unwrap(), v.permute_dyn(&[2, 1, 0]).unwrap());
+    assert_eq!(v.swap_axes(0, 3), Err(Error::IndexOutOfBounds));
+
+    let x = s.view(.., .., step(.., 2)).into_dyn();
+
+    assert_eq!(x.insert_axis(2).unwrap().remove_axis(1).unwrap(), view![[[0, 2]], [[10, 12]]]);
+    assert_eq!(x.squeeze().strides(), [3, 2]);
+
+    let mut v = t.expr_mut();
+
+    v.squeeze_mut()[[1, 2]] = 13;
+    v.insert_axis_mut(2).unwrap()[[1, 0, 0, 2]] += 1;
+    v.remove_axis_mut(1).unwrap()[[1, 1]] = 11;
+    v.permute_dyn_mut(&[2, 1, 0]).unwrap()[[0, 0, 1]] = 20;
+    v.swap_axes_mut(1, 2).unwrap()[[1, 1, 0]] = 21;
+
+    assert_eq!(t, view![[[0, 1, 2]], [[20, 21, 14]]].into_dyn());
+
+    t.insert_axis_in_place(1).unwrap();
+    t.insert_axis_in_place(4).unwrap();
+
+    assert_eq!(t.dims(), [2, 1, 1, 3, 1]);
+
+    t.remove_axis_in_place(2).unwrap();
+    t.squeeze_in_place();
+
+    assert_eq!(t, view![[0, 1, 2], [20, 21, 14]].into_dyn());
+    assert_eq!(t.insert_axis_in_place(3), Err(Error::IndexOutOfBounds));
+    assert_eq!(t.remove_axis_in_place(0), Err(Error::ShapeMismatch));
+
+    t.permute_dyn_in_place(&[1, 0]).unwrap();
+
+    assert_eq!(t, view![[0, 20], [1, 21], [2, 14]].into_dyn());
+    assert_eq!(t.insert_axis(0).unwrap().dims(), [1, 3, 2]);
+    assert_eq!(t.permute_dyn_in_place(&[1, 1]), Err(Error::InvalidPermutation));
+
+    let u = Tensor::from_fn([2, 3, 4], |i| (100 * i[0] + 10 * i[1] + i[2]).to_string());
+    let w = u.permute((Const::<2>, Const::<0>, Const::<1>)).to_tensor().into_dyn();
+
+    let mut u = u.into_dyn();
+
+    u.swap_axes_in_place(0, 2).unwrap();
+    u.swap_axes_in_place(1, 2).unwrap();
+
+    assert_eq!(u, w);
+    assert_eq!(u.swap_axes_in_place(1, 3), Err(Error::IndexOutOfBounds));
+    assert_eq!(Error::InvalidPermutation.to_string(), "invalid permutation");
+}
+
+#[allow(clippy::op_ref)]
 #[test]
 fn test_expr() {
     let mut a = tensor![[1, 2, 3], [4, 5, 6]];
@@ -429,6 +992,11 @@ fn test_expr() {
     assert_eq!(a.lanes(U0).eval(), view![view![1, 4], view![2, 5], view![3, 6]]);
     assert_eq!(a.lanes_mut(1).eval(), view![view![1, 2, 3], view![4, 5, 6]]);
 
+    let f = Tensor::from_fn([2, 3, 4], |i| 100 * i[0] + 10 * i[1] + i[2]);
+    let g = f.view(.., .., 1..).lanes(1).map(|x| x[2]).eval();
+
+    assert_eq!(g, view![[21, 22, 23], [121, 122, 123]]);
+
     assert_eq!(a.rows().eval(), view![view![1, 2, 3], view![4, 5, 6]]);
     assert_eq!(a.rows_mut().eval(), view![view![1, 2, 3], view![4, 5, 6]]);
 
@@ -437,6 +1005,118 @@ fn test_expr() {
     assert!(tensor![[1, 2, 3], [4, 5, 6]].expr().ne(view![[4, 5, 6], [1, 2, 3]]));
 }
 
+#[test]
+fn test_flip() {
+    let mut t = Tensor::from_fn([2, 3, 4], |i| 100 * i[0] + 10 * i[1] + i[2]);
+
+    assert_eq!(t.flip(0), t.view(step(.., -1), .., ..));
+    assert_eq!(t.flip(Const::<2>), t.view(.., .., step(.., -1)));
+    assert_eq!(t.flip(1).strides(), &[12, -4, 1]);
+    assert_eq!(t.flip(1).into_flipped(1), t);
+    assert_eq!(t.view(.., step(.., -2), ..).flip(1), t.view(.., step(.., 2), ..));
+
+    t.flip_mut(2)[[1, 2, 0]] = 999;
+
+    assert_eq!(t[[1, 2, 3]], 999);
+
+    let e = Tensor::<i32, _>::from_elem([0, 3], 0);
+
+    assert_eq!(e.flip(1).as_ptr(), e.as_ptr());
+    assert_eq!(e.flip(1).shape(), &(0, 3));
+
+    let a = array![[1, 2, 3], [4, 5, 6]];
+
+    assert_eq!(a.rot90(0, [0, 1]), a);
+    assert_eq!(a.rot90(1, [0, 1]), view![[3, 6], [2, 5], [1, 4]]);
+    assert_eq!(a.rot90(2, [0, 1]), view![[6, 5, 4], [3, 2, 1]]);
+    assert_eq!(a.rot90(3, [0, 1]), view![[4, 1], [5, 2], [6, 3]]);
+    assert_eq!(a.rot90(-1, [0, 1]), a.rot90(1, [1, 0]));
+    assert_eq!(a.rot90(5, [0, 1]).shape(), &(3, 2));
+
+    assert_eq!(t.rot90(1, [1, 2]).shape(), &(2, 4, 3));
+    assert_eq!(t.rot90(1, [1, 2])[[1, 0, 2]], t[[1, 2, 3]]);
+    assert_eq!(t.rot90(2, [0, 2]), t.flip(0).into_flipped(2));
+
+    t.rot90_mut(1, [1, 2])[[0, 3, 0]] = 0;
+
+    assert_eq!(t[[0, 0, 0]], 0);
+}
+
+#[test]
+fn test_format() {
+    let a = Tensor::<i32, _>::from_fn([2, 3], |i| (10 * i[0] + i[1]) as i32);
+    let b = Tensor::<f64, _>::from_fn([2, 2, 2], |i| (4 * i[0] + 2 * i[1] + i[2]) as f64 / 4.0);
+
+    assert_eq!(format!("{a}"), "[[ 0  1  2]\n [10 11 12]]");
+    assert_eq!(format!("{:<3}", a.view(1, ..)), "[10  11  12 ]");
+    assert_eq!(format!("{:+}", a.view(.., 0)), "[ +0 +10]");
+    assert_eq!(format!("{}", a.view(1, 2)), "12");
+    assert_eq!(format!("{}", Tensor::<i32, _>::from_elem([2, 0], 0)), "[[]\n []]");
+
+    assert_eq!(
+        format!("{b:.2}"),
+        "[[[0.00 0.25]\n  [0.50 0.75]]\n\n [[1.00 1.25]\n  [1.50 1.75]]]"
+    );
+
+    let c = Tensor::<usize, _>::from_fn([100, 100], |i| 100 * i[0] + i[1]);
+    let s = format!("{c}");
+
+    assert_eq!(s.lines().count(), 7);
+    assert_eq!(s.lines().next(), Some("[[   0    1    2 ...   97   98   99]"));
+    assert_eq!(s.lines().nth(3), Some(" ..."));
+
+    let options = PrintOptions { threshold: 8, edge_items: 1, line_width: 20 };
+
+    assert_eq!(
+        format!("{}", c.view(..3, ..3).display(options)),
+        "[[  0 ...   2]\n ...\n [200 ... 202]]"
+    );
+
+    let options = PrintOptions { line_width: 20, ..Default::default() };
+
+    assert_eq!(
+        format!("{}", c.view(0, ..12).display(options)),
+        "[ 0  1  2  3  4  5\n  6  7  8  9 10 11]"
+    );
+}
+
+#[test]
+fn test_from_raw_parts() {
+    let mut v = (0..12).collect::<Vec<i32>>();
+    let ptr = v.as_mut_ptr();
+
+    let a = unsafe { View::from_raw_parts(ptr, (3, 2), &[1, 4]) };
+
+    assert_eq!(a, view![[0, 4], [1, 5], [2, 6]]);
+
+    let b = unsafe { View::try_from_raw_parts_bytes(ptr.add(11), (2, 2), &[-8, -24]) };
+
+    assert_eq!(b.unwrap(), view![[11, 5], [9, 3]]);
+
+    let c = unsafe { View::try_from_raw_parts(ptr, (4, 3), &[0, 1]) };
+
+    assert_eq!(c.unwrap(), view![[0, 1, 2], [0, 1, 2], [0, 1, 2], [0, 1, 2]]);
+
+    unsafe {
+        assert_eq!(ViewMut::try_from_raw_parts(ptr, (4, 3), &[0, 1]), Err(Error::InvalidStrides));
+        assert_eq!(ViewMut::try_from_raw_parts(ptr, (2, 3), &[2, 1]), Err(Error::InvalidStrides));
+        assert_eq!(View::try_from_raw_parts_bytes(ptr, (3,), &[6]), Err(Error::InvalidStrides));
+        assert_eq!(View::try_from_raw_parts(ptr, (3,), &[1, 1]), Err(Error::RankMismatch));
+        assert_eq!(
+            View::try_from_raw_parts(ptr.byte_add(1), (3,), &[1]),
+            Err(Error::InvalidPointer)
+        );
+        assert_eq!(View::try_from_raw_parts(ptr, (3,), &[isize::MAX]), Err(Error::Overflow));
+
+        let mut d = ViewMut::try_from_raw_parts(ptr, (2, 3), &[3, 1]).unwrap();
+
+        d[[1, 2]] = 7;
+    }
+
+    assert_eq!(v[5], 7);
+    assert!(unsafe { ViewMut::<i32, _, _>::try_from_raw_parts(ptr, (0, 3), &[0, 0]) }.is_ok());
+}
+
 #[test]
 fn test_hash() {
     let mut s1 = DefaultHasher::new();
@@ -457,6 +1137,81 @@ fn test_index() {
     check_view::<Strided>();
 }
 
+#[cfg(feature = "num-traits")]
+#[test]
+fn test_linalg() {
+    use mdarray::linalg;
+
+    let close = |x: DTensor<f64, 2>, y: &DSlice<f64, 2>| {
+        x.iter().zip(y).all(|(a, b)| (a - b).abs() < 1e-12)
+    };
+
+    let a = tensor![[2.0, 1.0, 1.0], [4.0, -6.0, 0.0], [-2.0, 7.0, 2.0]];
+    let lu = linalg::lu(&a);
+
+    assert_eq!(lu.perm(), &[1, 0, 2]);
+    assert!(close(lu.l().matmul(&lu.u()), &a.take(0, lu.perm())));
+    assert!((lu.det() - linalg::det(&a.transpose())).abs() < 1e-12);
+    assert!((lu.det() + 16.0).abs() < 1e-12);
+
+    let x = linalg::solve(&a, &tensor![5.0, -2.0, 9.0]).unwrap();
+
+    assert!(close(x.into_shape([3, 1]), &view![[1.0], [1.0], [2.0]]));
+
+    let b = Tensor::from_fn([3, 2], |i| (i[0] + i[1]) as f64);
+    let x = linalg::solve(&a.transpose(), &b).unwrap();
+
+    assert!(close(a.transpose().matmul(&x), &b));
+    assert!(close(a.matmul(&linalg::inv(&a).unwrap()), &Tensor::eye(3)));
+
+    let v = a.view(step(.., -1), ..);
+
+    assert!(close(v.matmul(&lu.solve(&b.view(step(.., -1), ..)).unwrap()), &b));
+
+    let s = tensor![[1.0, 2.0], [2.0, 4.0]];
+
+    assert_eq!(linalg::det(&s), 0.0);
+    assert_eq!(linalg::solve(&s, &tensor![1.0, 2.0]), Err(Error::Singular));
+    assert_eq!(linalg::inv(&s), Err(Error::Singular));
+    assert_eq!(linalg::det(&DTensor::<f64, 2>::eye(0)), 1.0);
+
+    let p = tensor![[4.0, 2.0, 0.4], [2.0, 5.0, 1.0], [0.4, 1.0, 3.0]];
+    let l = linalg::cholesky(&p).unwrap();
+
+    assert_eq!(l[[0, 1]], 0.0);
+    assert!(close(l.matmul(&l.transpose()), &p));
+    assert_eq!(linalg::cholesky(&s), Err(Error::NotPositiveDefinite));
+    assert_eq!(linalg::cholesky(&a), Err(Error::NotPositiveDefinite));
+
+    let y = linalg::solve_lower(&l, &tensor![1.0, 2.0, 3.0]).unwrap();
+
+    assert!(close(l.matmul(&y.clone().into_shape([3, 1])), &view![[1.0], [2.0], [3.0]]));
+    assert!(close(
+        linalg::solve_upper(&l.transpose(), &b).unwrap(),
+        &linalg::solve(&l.transpose(), &b).unwrap()
+    ));
+    assert_eq!(
+        linalg::solve_upper(&tensor![[1.0, 2.0], [0.0, 0.0]], &tensor![1.0, 2.0]),
+        Err(Error::Singular)
+    );
+
+    let m = Tensor::from_fn([4, 3], |i| ((i[0] * 3 + i[1]) as f64).sin());
+
+    for c in [m.clone(), m.transpose().to_tensor(), m.view(.., ..1).to_tensor()] {
+        let (q, r) = linalg::qr(&c);
+        let k = c.dim(0).min(c.dim(1));
+
+        assert_eq!(q.shape(), &(c.dim(0), k));
+        assert_eq!(r.shape(), &(k, c.dim(1)));
+        assert!(close(q.matmul(&r), &c));
+        assert!(close(q.transpose().matmul(&q), &Tensor::eye(k)));
+
+        for i in 0..k {
+            assert!(r.view(i, ..i).iter().all(|&x| x == 0.0));
+        }
+    }
+}
+
 #[test]
 fn test_macros() {
     let array1: Array<usize, _> = array![];
@@ -530,6 +1285,7 @@ fn test_macros() {
     assert_eq!(view![[[[[[0; 1]; 2]; 3]; 4]; 5]; 6], array![[[[[[0; 1]; 2]; 3]; 4]; 5]; 6]);
 }
 
+#[allow(clippy::bool_assert_comparison)]
 #[test]
 fn test_mapping() {
     let d = DenseMapping::new((U1, 2, U3));
@@ -552,6 +1308,368 @@ fn test_mapping() {
     assert_eq!(y, "StridedMapping { shape: DynRank([1, 2, 3]), strides: [4, 5, 6] }");
 }
 
+#[test]
+fn test_matmul() {
+    fn naive(a: &DSlice<i64, 2, impl Layout>, b: &DSlice<i64, 2, impl Layout>) -> DTensor<i64, 2> {
+        DTensor::<i64, 2>::from_fn([a.dim(0), b.dim(1)], |i| {
+            (0..a.dim(1)).map(|k| a[[i[0], k]] * b[[k, i[1]]]).sum()
+        })
+    }
+
+    let a = DTensor::<i64, 2>::from_fn([67, 260], |i| (i[0] as i64 * 7 + i[1] as i64 * 3) % 11 - 5);
+    let b = DTensor::<i64, 2>::from_fn([260, 30], |i| (i[0] as i64 * 5 + i[1] as i64) % 13 - 6);
+
+    assert_eq!(a.matmul(&b), naive(&a, &b));
+    assert_eq!(
+        b.transpose().matmul(&a.view(..2, ..).transpose()),
+        naive(&b.transpose(), &a.transpose().view(.., ..2))
+    );
+
+    let w = DTensor::<i64, 2>::from_fn([3, 600], |i| (i[0] + i[1]) as i64 % 7);
+
+    assert_eq!(b.view(..3, ..3).matmul(&w), naive(&b.view(..3, ..3), &w));
+
+    let c = b.view(.., step(.., 2)).to_tensor();
+
+    assert_eq!(c.transpose().matmul(&a.transpose()), naive(&c.transpose(), &a.transpose()));
+    assert_eq!(
+        a.view(.., 1..).matmul(&b.view(..259, ..5)),
+        naive(&a.view(.., 1..), &b.view(..259, ..5))
+    );
+
+    let d = array![[1, 2], [3, 4]];
+    let e = array![[5, 6], [7, 8]];
+
+    assert_eq!(d.matmul(&e), view![[19, 22], [43, 50]]);
+    assert_eq!(d.matmul(&e).shape(), &(Const::<2>, Const::<2>));
+
+    let f = Tensor::<i64, (Dyn, Dyn, Dyn)>::from_fn([3, 4, 5], |i| {
+        (i[0] * 20 + i[1] * 5 + i[2]) as i64
+    });
+    let g = Tensor::<i64, (Dyn, Dyn, Dyn)>::from_fn([1, 5, 2], |i| (i[1] + i[2]) as i64);
+
+    let h = f.matmul(&g);
+
+    assert_eq!(h.shape(), &(3, 4, 2));
+
+    for i in 0..3 {
+        assert_eq!(h.view(i, .., ..), naive(&f.view(i, .., ..), &g.view(0, .., ..)));
+    }
+
+    let x = Tensor::<i64, _>::from_fn([2, 3, 4], |i| (i[0] * 12 + i[1] * 4 + i[2]) as i64);
+    let y = Tensor::<i64, _>::from_fn([4, 5, 2], |i| (i[0] + 2 * i[1] + 3 * i[2]) as i64);
+
+    let z = x.tensordot(&y, &[(2, 0), (0, 2)]);
+
+    assert_eq!(z.dims(), &[3, 5]);
+
+    for i in 0..3 {
+        for j in 0..5 {
+            let sum = (0..4).flat_map(|k| (0..2).map(move |l| (k, l)));
+            let sum = sum.map(|(k, l)| x[[l, i, k]] * y[[k, j, l]]).sum::<i64>();
+
+            assert_eq!(z[[i, j]], sum);
+        }
+    }
+
+    assert_eq!(x.tensordot(&y, &[]).dims(), &[2, 3, 4, 4, 5, 2]);
+    assert_eq!(a.tensordot(&b, &[(1, 0)]), a.matmul(&b).into_dyn());
+}
+
+#[cfg(feature = "memmap")]
+#[test]
+fn test_mmap() {
+    use std::fs::File;
+    use std::io::ErrorKind;
+
+    use mdarray::npy::{self, MmapTensor, MmapTensorMut};
+    use memmap2::MmapMut;
+
+    fn mmap(buf: &[u8]) -> MmapMut {
+        let mut mmap = MmapMut::map_anon(buf.len()).unwrap();
+
+        mmap.copy_from_slice(buf);
+        mmap
+    }
+
+    fn patch(buf: &[u8], from: &[u8], to: &[u8]) -> Vec<u8> {
+        let index = buf.windows(from.len()).position(|x| x == from).unwrap();
+        let mut buf = buf.to_vec();
+
+        buf[index..index + to.len()].copy_from_slice(to);
+        buf
+    }
+
+    let path = std::env::temp_dir().join(format!("mdarray_test_{}.npy", std::process::id()));
+    let mut t = unsafe { MmapTensorMut::<f64, (Dyn, Dyn, Dyn)>::create(&path, [2, 3, 4]).unwrap() };
+
+    assert_eq!(t.shape(), &(2, 3, 4));
+    assert!(t.iter().all(|&x| x == 0.0));
+
+    t.assign(expr::from_fn([2, 3, 4], |i| (12 * i[0] + 4 * i[1] + i[2]) as f64));
+    t.flush().unwrap();
+    t[[0, 0, 0]] = 0.5;
+
+    drop(t);
+
+    let t = unsafe { MmapTensor::<f64, (Dyn, Dyn, Dyn)>::open(&path).unwrap() };
+    let u: DTensor<f64, 3> = npy::read(File::open(&path).unwrap()).unwrap();
+
+    assert_eq!(*t, u);
+    assert_eq!(t[[0, 0, 0]], 0.5);
+    assert_eq!(t.view(1, .., 2), view![14.0, 18.0, 22.0]);
+    assert_eq!(t.axis_expr(0).into_iter().map(|x| x[[2, 3]]).collect::<Vec<_>>(), [11.0, 23.0]);
+    assert_eq!(t.lanes(2).map(|x| x.iter().sum::<f64>()).eval()[[1, 1]], 70.0);
+
+    drop(t);
+
+    let mut t = unsafe { MmapTensorMut::<f64, (Dyn, Dyn, Dyn)>::open(&path).unwrap() };
+
+    t.view_mut(.., 0, ..).fill(1.0);
+
+    drop(t);
+
+    let t = unsafe { MmapTensor::<f64>::open(&path).unwrap() };
+
+    assert_eq!(t.dims(), &[2, 3, 4]);
+    assert_eq!((t[[1, 0, 0]], t[[1, 1, 0]]), (1.0, 16.0));
+
+    drop(t);
+    std::fs::remove_file(&path).unwrap();
+
+    let mut buf = Vec::new();
+
+    npy::write(&mut buf, &tensor![[true, false], [false, true]]).unwrap();
+
+    let t =
+        MmapTensor::<bool, (Dyn, Dyn)>::from_mmap(mmap(&buf).make_read_only().unwrap()).unwrap();
+
+    assert_eq!(*t, view![[true, false], [false, true]]);
+
+    let kind = |e: std::io::Error| e.kind();
+    let len = buf.len();
+
+    buf[len - 1] = 2;
+    assert_eq!(
+        MmapTensorMut::<bool>::from_mmap(mmap(&buf)).map_err(kind).unwrap_err(),
+        ErrorKind::InvalidData
+    );
+    assert_eq!(
+        MmapTensorMut::<bool>::from_mmap(mmap(&buf[..len - 1])).map_err(kind).unwrap_err(),
+        ErrorKind::UnexpectedEof
+    );
+    assert_eq!(
+        MmapTensorMut::<u8>::from_mmap(mmap(&buf)).map_err(kind).unwrap_err(),
+        ErrorKind::InvalidData
+    );
+
+    let buf = patch(&buf, b"False", b"True ");
+
+    assert_eq!(
+        MmapTensorMut::<bool>::from_mmap(mmap(&buf)).map_err(kind).unwrap_err(),
+        ErrorKind::Unsupported
+    );
+
+    let mut buf = Vec::new();
+
+    npy::write(&mut buf, &tensor![1u16, 2]).unwrap();
+
+    let buf = patch(&buf, b"<u2", b">u2");
+
+    assert_eq!(
+        MmapTensorMut::<u16>::from_mmap(mmap(&buf)).map_err(kind).unwrap_err(),
+        ErrorKind::Unsupported
+    );
+}
+
+#[cfg(feature = "npy")]
+#[test]
+fn test_npy() {
+    use std::io::ErrorKind;
+
+    use mdarray::npy;
+
+    fn npy_file(dict: &str, data: &[u8]) -> Vec<u8> {
+        let mut buf = b"\x93NUMPY\x01\x00".to_vec();
+        let len = (dict.len() + 11).next_multiple_of(64) - 10;
+
+        buf.extend_from_slice(&(len as u16).to_le_bytes());
+        buf.extend_from_slice(dict.as_bytes());
+        buf.resize(len + 9, b' ');
+        buf.push(b'\n');
+        buf.extend_from_slice(data);
+        buf
+    }
+
+    let t = tensor![[1, 2, 3], [4, 5, 6]];
+    let mut buf = Vec::new();
+
+    npy::write(&mut buf, &t).unwrap();
+
+    let dict = "{'descr': '<i4', 'fortran_order': False, 'shape': (2, 3), }";
+    let data = (1..7).flat_map(i32::to_le_bytes).collect::<Vec<_>>();
+
+    assert_eq!(buf, npy_file(dict, &data));
+    assert_eq!(buf.len() % 64, 24);
+
+    assert_eq!(npy::read::<i32, (Dyn, U3), _>(&buf[..]).unwrap(), t);
+    assert_eq!(npy::read::<i32, DynRank, _>(&buf[..]).unwrap(), t.view(.., ..).into_dyn());
+
+    let err = npy::read::<i32, (Dyn,), _>(&buf[..]).unwrap_err();
+
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert_eq!(err.into_inner().unwrap().downcast_ref::<Error>(), Some(&Error::RankMismatch));
+    assert!(npy::read::<i32, (Dyn, U2), _>(&buf[..]).is_err());
+    assert!(npy::read::<f32, DynRank, _>(&buf[..]).is_err());
+    assert!(npy::read::<i32, DynRank, _>(&buf[..buf.len() - 1]).is_err());
+
+    buf.clear();
+    npy::write(&mut buf, &t.view(.., 1..).transpose()).unwrap();
+
+    assert_eq!(npy::read::<i32, (Dyn, Dyn), _>(&buf[..]).unwrap(), view![[2, 5], [3, 6]]);
+
+    buf.clear();
+    npy::write(&mut buf, &Array::<f64, ()>(1.5)).unwrap();
+
+    assert_eq!(npy::read::<f64, (), _>(&buf[..]).unwrap()[[]], 1.5);
+
+    buf.clear();
+    npy::write(&mut buf, &tensor![true, false]).unwrap();
+
+    assert_eq!(npy::read::<bool, (Dyn,), _>(&buf[..]).unwrap(), view![true, false]);
+
+    let dict = "{'descr': '>u2', 'fortran_order': True, 'shape': (2, 3)}";
+    let data = (1..7u16).flat_map(u16::to_be_bytes).collect::<Vec<_>>();
+
+    let u = npy::read::<u16, DynRank, _>(&npy_file(dict, &data)[..]).unwrap();
+
+    assert_eq!(u, view![[1, 3, 5], [2, 4, 6]].into_dyn());
+
+    let dict = "{'descr': '|u1', 'fortran_order': False, 'shape': (4,)}";
+    let u = npy::read::<u8, (Dyn,), _>(&npy_file(dict, &[1, 2, 3, 4])[..]).unwrap();
+
+    assert_eq!(u, view![1, 2, 3, 4]);
+
+    let dict = "{'descr': '<i8', 'shape': (4,)}";
+
+    assert!(npy::read::<i64, (Dyn,), _>(&npy_file(dict, &[])[..]).is_err());
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Large([u8; 10000]);
+
+    unsafe impl npy::Element for Large {
+        const DESCR: &'static str = "|V10000";
+
+        fn from_le_bytes(bytes: &[u8]) -> std::io::Result<Self> {
+            Ok(Large(bytes.try_into().unwrap()))
+        }
+
+        fn to_le_bytes(self, bytes: &mut [u8]) {
+            bytes.copy_from_slice(&self.0);
+        }
+    }
+
+    let l = tensor![Large([1; 10000]), Large([2; 10000])];
+
+    buf.clear();
+    npy::write(&mut buf, &l).unwrap();
+
+    assert_eq!(npy::read::<Large, (Dyn,), _>(&buf[..]).unwrap(), l);
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Empty;
+
+    unsafe impl npy::Element for Empty {
+        const DESCR: &'static str = "|V0";
+
+        fn from_le_bytes(_: &[u8]) -> std::io::Result<Self> {
+            Ok(Empty)
+        }
+
+        fn to_le_bytes(self, _: &mut [u8]) {}
+    }
+
+    let dict = "{'descr': '|V0', 'fortran_order': False, 'shape': (2,)}";
+    let err = npy::read::<Empty, (Dyn,), _>(&npy_file(dict, &[])[..]).unwrap_err();
+
+    assert_eq!(err.kind(), ErrorKind::Unsupported);
+    assert_eq!(npy::write(&mut buf, &tensor![Empty]).unwrap_err().kind(), ErrorKind::Unsupported);
+
+    #[cfg(feature = "num-complex")]
+    {
+        use num_complex::Complex;
+
+        let c = tensor![Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)];
+
+        buf.clear();
+        npy::write(&mut buf, &c).unwrap();
+
+        assert_eq!(npy::read::<Complex<f64>, (Dyn,), _>(&buf[..]).unwrap(), c);
+
+        let dict = "{'descr': '>c8', 'fortran_order': False, 'shape': (2,)}";
+        let data = [1f32, 2.0, 3.0, 4.0].into_iter().flat_map(f32::to_be_bytes).collect::<Vec<_>>();
+
+        let u = npy::read::<Complex<f32>, (Dyn,), _>(&npy_file(dict, &data)[..]).unwrap();
+
+        assert_eq!(u, view![Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)]);
+    }
+
+    #[cfg(feature = "npz")]
+    {
+        use std::io::Cursor;
+
+        let mut writer = npy::NpzWriter::new(Cursor::new(Vec::new()));
+
+        writer.write("a", &t).unwrap();
+        writer.write("b", &view![0.5f32, 1.5]).unwrap();
+
+        let mut reader = npy::NpzReader::new(writer.finish().unwrap()).unwrap();
+
+        assert_eq!(reader.names().collect::<Vec<_>>(), ["a", "b"]);
+        assert_eq!(reader.read::<i32, (Dyn, Dyn)>("a").unwrap(), t);
+        assert_eq!(reader.read::<f32, (Dyn,)>("b").unwrap(), view![0.5, 1.5]);
+        assert!(reader.read::<f32, (Dyn,)>("c").is_err());
+    }
+}
+
+#[cfg(feature = "num-traits")]
+#[test]
+fn test_num() {
+    use mdarray::expr::NumExpression;
+
+    assert_eq!(Tensor::<i32, _>::ones([2, 3]), view![[1, 1, 1], [1, 1, 1]]);
+    assert_eq!(Array::<f64, (Const<2>,)>::ones(), view![1.0, 1.0]);
+
+    assert_eq!(DTensor::<i32, 2>::eye(3), view![[1, 0, 0], [0, 1, 0], [0, 0, 1]]);
+    assert_eq!(Array::<u8, (Const<2>, Const<2>)>::eye(), view![[1, 0], [0, 1]]);
+
+    assert_eq!(Tensor::arange(2u32, 9, 2), view![2, 4, 6, 8]);
+    assert_eq!(Tensor::arange(3, -3, -2), view![3, 1, -1]);
+    assert_eq!(Tensor::arange(0.0, 1.0, 0.3), view![0.0, 0.3, 0.6, 0.8999999999999999]);
+    assert!(Tensor::arange(5u8, 1, 1).is_empty());
+
+    assert_eq!(Tensor::linspace(1.0, 2.0, 3), view![1.0, 1.5, 2.0]);
+    assert_eq!(Tensor::linspace(1.0, 2.0, 1), view![1.0]);
+    assert!(Tensor::<f32, _>::linspace(1.0, 2.0, 0).is_empty());
+    assert_eq!(Array::<f64, (Const<3>,)>::linspace(-1.0, 0.0), view![-1.0, -0.5, 0.0]);
+
+    let a = tensor![[-1, 2], [3, -4]];
+    let b = tensor![[0.0, 1.0], [4.0, 9.0]];
+
+    assert_eq!(a.abs().eval(), view![[1, 2], [3, 4]]);
+    assert_eq!(b.sqrt().eval(), view![[0.0, 1.0], [2.0, 3.0]]);
+    assert_eq!(b.view(1, ..).powi(2).eval(), view![16.0, 81.0]);
+    assert_eq!(b.view(1, ..).powf(0.5).eval(), view![2.0, 3.0]);
+    assert_eq!(b.view(0, ..).exp().eval(), view![1.0, 1.0f64.exp()]);
+    assert_eq!(b.view(0, 1..).ln().eval(), view![0.0]);
+    assert_eq!(
+        (b.view(0, ..).sin() + b.view(0, ..).cos()).eval(),
+        view![1.0, 1.0f64.sin() + 1.0f64.cos()]
+    );
+    assert_eq!(b.view(0, ..1).tan().eval(), view![0.0]);
+}
+
+#[allow(clippy::assign_op_pattern, clippy::nonminimal_bool)]
 #[test]
 fn test_ops() {
     let mut a = DTensor::<i32, 2>::from([[1, 2, 3], [4, 5, 6]]);
@@ -611,6 +1729,112 @@ fn test_ops() {
     assert_eq!(c.eval(), tensor![[5, 6], [6, 7], [7, 8]]);
 }
 
+#[test]
+fn test_overflow() {
+    let big = 1 << (usize::BITS - 2);
+
+    assert_eq!(DTensor::<u8, 2>::try_from_elem([big, 4], 0), Err(Error::Overflow));
+    assert_eq!(DTensor::<u8, 2>::try_from_elem([big, 2], 0), Err(Error::Overflow));
+    assert_eq!(
+        DTensor::<u8, 2>::try_from_fn([2, 3], |i| i[1] as u8),
+        Ok(tensor![[0, 1, 2], [0, 1, 2]])
+    );
+    assert_eq!(Tensor::<u64, _>::try_zeros([big / 4]), Err(Error::Overflow));
+    assert_eq!(Tensor::<(), _>::try_zeros([big, 2]).map(|t| t.len()), Err(Error::Overflow));
+    assert!(Tensor::<u16, DynRank>::try_uninit(&[big, 1][..]).is_err());
+
+    let mut t = Tensor::<u8, _>::try_zeros([0, 3]).unwrap();
+
+    assert_eq!(t.try_resize(&[2, 3, 4], 1), Err(Error::RankMismatch));
+    assert_eq!(t.try_resize(&[big, 3], 1), Err(Error::Overflow));
+    assert_eq!(t.shape(), &(0, 3));
+
+    t.try_resize(&[2, 2], 1).unwrap();
+
+    assert_eq!(t, view![[1, 1], [1, 1]]);
+    assert_eq!(t.try_into_shape([big, 2]).unwrap_err().1, Error::Overflow);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_par() {
+    use mdarray::expr::ParExpression;
+    use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+    let a = Tensor::<usize, _>::from_fn([37, 5, 3], |i| 100 * i[0] + 10 * i[1] + i[2]);
+
+    assert_eq!(a.expr().map(|x| 2 * x).par_eval(), a.expr().map(|x| 2 * x).eval());
+    assert_eq!(a.expr().enumerate().par_eval(), a.expr().enumerate().eval());
+    assert_eq!(a.view(1.., .., 1).expr().cloned().par_eval(), a.view(1.., .., 1));
+    assert_eq!(a.permute([2, 0, 1]).expr().copied().par_eval(), a.permute([2, 0, 1]));
+    assert_eq!(expr::from_fn([37, 5, 3], |i| 100 * i[0] + 10 * i[1] + i[2]).par_eval(), a);
+
+    assert_eq!(a.expr().par_fold(|| 0, |acc, x| acc + x, |x, y| x + y), a.iter().sum());
+    assert_eq!(a.expr().copied().par_reduce(|| 0, usize::max), 3642);
+
+    let mut b = Tensor::<usize, _>::from_elem([37, 5, 3], 0);
+
+    b.expr_mut().par_for_each(|x| *x = 1);
+    b.par_assign(expr::zip(&a, view![[1], [2], [3], [4], [5]]).map(|(x, y)| x * y));
+
+    assert_eq!(b, (&a * view![[1], [2], [3], [4], [5]]).eval());
+
+    b.par_assign(&a.view(..1, .., ..));
+
+    assert_eq!(b, a.view(..1, .., ..).broadcast_to([37, 5, 3]).to_tensor());
+
+    b.rows_mut().into_par_iter().for_each(|mut x| x.assign(view![1, 2, 3]));
+
+    assert_eq!(b, expr::from_fn([37, 5, 3], |i| i[2] + 1).eval());
+    assert_eq!(b.outer_expr().into_par_iter().map(|x| x[[0, 0]]).sum::<usize>(), 37);
+
+    let mut c = Tensor::<usize, _>::from_elem([2, 3], 1);
+
+    c.expr_mut().zip(expr::fill_with(|| 2)).par_for_each(|(x, y)| *x += y);
+
+    assert_eq!(c, view![[3, 3, 3], [3, 3, 3]]);
+}
+
+#[test]
+fn test_reduce() {
+    let a = Tensor::<i32, _>::from_fn([2, 3, 4], |i| (100 * i[0] + 10 * i[1] + i[2]) as i32);
+
+    assert_eq!(a.sum_axis(0).shape(), &(3, 4));
+    assert_eq!(a.sum_axis(0).view(2, ..), view![140, 142, 144, 146]);
+    assert_eq!(a.sum_axis(U1).view(1, ..), view![330, 333, 336, 339]);
+    assert_eq!(a.sum_axis(Rows), view![[6, 46, 86], [406, 446, 486]]);
+    assert_eq!(a.sum_axis_keepdims(Cols).shape(), &(2, 1, 4));
+
+    let v = a.view(.., .., 1..);
+    let b = &*v;
+
+    assert_eq!(b.max_axis(U0), view![[101, 102, 103], [111, 112, 113], [121, 122, 123]]);
+    assert_eq!(b.min_axis(U0), view![[1, 2, 3], [11, 12, 13], [21, 22, 23]]);
+    assert_eq!(b.prod_axis(2), view![[6, 1716, 10626], [1061106, 1404816, 1815726]]);
+    assert_eq!(b.min_axis_keepdims(2), view![[[1], [11], [21]], [[101], [111], [121]]]);
+
+    let c = array![[1.0, 4.0], [2.0, 8.0], [3.0, 3.0]];
+
+    assert_eq!(c.mean_axis(0), view![2.0, 5.0]);
+    assert_eq!(c.mean_axis_keepdims(Rows), view![[2.5], [5.0], [3.0]]);
+    assert_eq!(c.max_axis_keepdims(0), view![[3.0, 8.0]]);
+    assert_eq!(Tensor::from_elem([2, 100_003], 0.5f32).mean_axis(1), view![0.5, 0.5]);
+    assert!(DTensor::<f64, 2>::zeros([2, 0]).mean_axis(1).iter().all(|x| x.is_nan()));
+
+    let d = expr::from_fn([3, 2], |i| (i[0] + 2 * i[1]) as f64);
+
+    assert_eq!(d.clone().sum_axis(Cols), view![3.0, 9.0]);
+    assert_eq!(d.clone().mean_axis(Rows), view![1.0, 2.0, 3.0]);
+    assert_eq!(d.clone().prod_axis_keepdims(0), view![[0.0, 24.0]]);
+    assert_eq!(d.max_axis(1), view![2.0, 3.0, 4.0]);
+
+    let e = DTensor::<i32, 2>::from([[0; 3]; 0]);
+
+    assert_eq!(e.sum_axis(0), view![0, 0, 0]);
+    assert_eq!(e.prod_axis(0), view![1, 1, 1]);
+    assert_eq!(e.max_axis(1).shape(), &(0,));
+}
+
 #[cfg(feature = "serde")]
 #[test]
 fn test_serde() {
@@ -689,6 +1913,119 @@ fn test_serde() {
             Token::SeqEnd,
         ],
     );
+
+    assert_de_tokens(
+        &tensor![[1, 2]],
+        &[
+            Token::Seq { len: Some(usize::MAX / 2) },
+            Token::Seq { len: Some(usize::MAX) },
+            Token::I32(1),
+            Token::I32(2),
+            Token::SeqEnd,
+            Token::SeqEnd,
+        ],
+    );
+
+    assert_de_tokens_error::<Tensor<i32>>(&[Token::I32(1)], "dynamic rank not supported");
+
+    assert_de_tokens_error::<DTensor<i32, 2>>(
+        &[
+            Token::Seq { len: Some(2) },
+            Token::Seq { len: Some(1) },
+            Token::I32(1),
+            Token::SeqEnd,
+            Token::Seq { len: Some(0) },
+            Token::SeqEnd,
+        ],
+        "invalid dimensions [0], expected [1]",
+    );
+
+    assert_de_tokens_error::<Array<i32, (U2,)>>(
+        &[Token::Seq { len: Some(1) }, Token::I32(1), Token::SeqEnd],
+        "invalid dimension 1, expected 2",
+    );
+}
+
+#[test]
+fn test_sort() {
+    let t = tensor![[3, 1, 2], [1, 2, 1], [0, 5, 0]];
+
+    assert_eq!(t.argsort_axis(0), view![[2, 0, 2], [1, 1, 1], [0, 2, 0]]);
+    assert_eq!(t.argsort_axis(1), view![[1, 2, 0], [0, 2, 1], [0, 2, 1]]);
+    assert_eq!(t.argmax_axis(0), view![0, 2, 0]);
+    assert_eq!(t.argmin_axis(Rows), view![1, 0, 0]);
+    assert_eq!(t.argmin_axis(Const::<0>), view![2, 0, 2]);
+
+    let mut u = t.clone();
+
+    u.sort_axis(Rows);
+
+    assert_eq!(u, view![[1, 2, 3], [1, 1, 2], [0, 0, 5]]);
+
+    let mut u = t.clone();
+
+    u.view_mut(.., step(.., -1)).sort_axis(1);
+
+    assert_eq!(u, view![[3, 2, 1], [2, 1, 1], [5, 0, 0]]);
+
+    // Stable sort of strided lanes, where only the first element is compared.
+    let mut v = Tensor::from_fn([4, 2], |i| [(4 - i[0]) / 2, i[0]]);
+
+    v.sort_axis_by(0, |a, b| a[0].cmp(&b[0]));
+
+    assert_eq!(v.col(1).map(|x| x[1]).eval(), view![3, 1, 2, 0]);
+
+    let mut w =
+        Tensor::from_fn([2, 7], |i| String::from("gfedcba").as_bytes()[(i[1] + 3 * i[0]) % 7]);
+
+    w.transpose_mut().select_nth_axis(0, 2);
+
+    assert_eq!(w[[0, 2]], b'c');
+    assert_eq!(w[[1, 2]], b'c');
+    assert!(w.view(.., ..2).iter().all(|&x| x < b'c'));
+    assert!(w.view(.., 3..).iter().all(|&x| x > b'c'));
+
+    let mut e = DTensor::<i32, 2>::from_elem([0, 3], 0);
+
+    e.sort_axis(0);
+
+    assert_eq!(e.argsort_axis(0).shape(), &(0, 3));
+}
+
+#[test]
+fn test_take() {
+    let a = Tensor::<i32, _>::from_fn([3, 4, 2], |i| (100 * i[0] + 10 * i[1] + i[2]) as i32);
+
+    let b = a.take(Const::<1>, &[3, 0, 3]);
+
+    assert_eq!(b.shape(), &(3, 3, 2));
+    assert_eq!(
+        b,
+        Tensor::from_fn([3, 3, 2], |i| (100 * i[0] + 10 * [3, 0, 3][i[1]] + i[2]) as i32)
+    );
+    assert_eq!(a.take(Const::<0>, &[2]), a.view(2.., .., ..));
+    assert_eq!(a.take(2, &[1, 0]), a.view(.., .., step(.., -1)));
+    assert!(a.take(Cols, &[]).is_empty());
+
+    let mut c = Tensor::<i32, _>::from_elem([4, 3], 0);
+
+    c.put(Cols, &[3, 1], &tensor![[1, 2, 3], [4, 5, 6]]);
+    c.put(Rows, &[0], &view![[7], [8], [9], [10]]);
+
+    assert_eq!(c, view![[7, 0, 0], [8, 5, 6], [9, 0, 0], [10, 2, 3]]);
+
+    let mask = c.expr().map(|x| *x > 5).eval();
+
+    assert_eq!(c.select_mask(&mask), view![7, 8, 6, 9, 10]);
+    assert_eq!(c.view(.., 1..).select_mask(&mask.view(.., 1..)), view![6]);
+
+    c.assign_mask(&mask, expr::from_elem(5, -1));
+
+    assert_eq!(c, view![[-1, 0, 0], [-1, 5, -1], [-1, 0, 0], [-1, 2, 3]]);
+
+    c.assign_mask(&mask.transpose().to_tensor().transpose(), &view![1, 2, 3, 4, 5]);
+
+    assert_eq!(c, view![[1, 0, 0], [2, 5, 3], [4, 0, 0], [5, 2, 3]]);
 }
 
 #[test]
@@ -711,3 +2048,122 @@ fn test_traits() {
     assert_eq!(ptr, u.as_ptr());
     assert_ne!(ptr, v.as_ptr());
 }
+
+#[test]
+fn test_try_ops() {
+    let mut t = tensor![[1, 2, 3], [4, 5, 6]];
+
+    assert_eq!(t.try_reshape([3, 2]).unwrap(), view![[1, 2], [3, 4], [5, 6]]);
+    assert_eq!(t.try_reshape([!0, 3, 1]).unwrap().shape(), &(2, 3, 1));
+    assert_eq!(t.try_reshape([4, 2]).unwrap_err(), Error::ShapeMismatch);
+    assert_eq!(t.try_reshape([!0, !0]).unwrap_err(), Error::ShapeMismatch);
+    assert_eq!(t.try_reshape([!0, 0]).unwrap_err(), Error::ShapeMismatch);
+    assert_eq!(t.try_reshape([usize::MAX / 2, 3]).unwrap_err(), Error::Overflow);
+    assert_eq!(t.try_reshape_mut([6]).unwrap(), view![1, 2, 3, 4, 5, 6]);
+    assert_eq!(t.try_remap_mut::<DynRank, Strided>().unwrap().dims(), &[2, 3]);
+
+    let v = t.view(.., ..2);
+
+    assert_eq!(v.try_reshape([2, 1, 2]).unwrap(), view![[[1, 2]], [[4, 5]]]);
+    assert_eq!(v.try_reshape([4]).unwrap_err(), Error::NotContiguous);
+    assert_eq!(v.try_into_shape([4]).unwrap_err(), Error::NotContiguous);
+
+    assert_eq!(t.try_remap::<(Dyn, U3), Dense>().unwrap(), t);
+    assert_eq!(t.try_remap::<(Dyn, U2), Dense>().unwrap_err(), Error::ShapeMismatch);
+    assert_eq!(t.try_remap::<(Dyn,), Dense>().unwrap_err(), Error::RankMismatch);
+    assert_eq!(v.try_remap::<(Dyn, Dyn), Dense>().unwrap_err(), Error::NotContiguous);
+    assert_eq!(v.try_into_mapping::<(Dyn, U2), Strided>().unwrap(), view![[1, 2], [4, 5]]);
+
+    let c = Tensor::<i32, _>::from_fn_transposed([2, 3], |i| (10 * i[0] + i[1]) as i32);
+    let c = c.col_major();
+
+    assert_eq!(c.try_reshape([1, 2, 3]).unwrap(), view![[[0, 1, 2], [10, 11, 12]]]);
+    assert_eq!(c.try_reshape([3, 2]).unwrap_err(), Error::NotContiguous);
+    assert_eq!(c.try_remap::<(Dyn, Dyn), DenseColMajor>().unwrap(), c);
+    assert_eq!(v.try_remap::<(Dyn, Dyn), DenseColMajor>().unwrap_err(), Error::NotContiguous);
+
+    let (a, b) = t.try_split_at(1).unwrap();
+
+    assert_eq!((a, b), (view![[1, 2, 3]], view![[4, 5, 6]]));
+    assert_eq!(t.try_split_at(3).unwrap_err(), Error::IndexOutOfBounds);
+    assert_eq!(t.try_split_at_mut(2).unwrap().1.shape(), &(0, 3));
+    assert_eq!(t.view(.., 1).try_into_split_at(3).unwrap_err(), Error::IndexOutOfBounds);
+    assert!(Tensor::<i32, _>::from_elem((), 1).try_split_at(0).is_err());
+
+    let (a, b) = t.try_split_axis_at(U1, 2).unwrap();
+
+    assert_eq!(a, view![[1, 2], [4, 5]]);
+    assert_eq!(b, view![[3], [6]]);
+    assert_eq!(t.try_split_axis_at(2, 0).unwrap_err(), Error::IndexOutOfBounds);
+    assert_eq!(t.try_split_axis_at(U1, 4).unwrap_err(), Error::IndexOutOfBounds);
+    assert_eq!(t.try_split_axis_at_mut(U2, 0).unwrap_err(), Error::RankMismatch);
+    assert_eq!(t.try_split_axis_at_mut(1, 3).unwrap().1.shape(), &(2, 0));
+    assert_eq!(t.view(.., ..).try_into_split_axis_at(0, 1).unwrap().1, view![[4, 5, 6]]);
+    assert_eq!(t.view(.., 1).try_into_split_axis_at(Cols, 0).unwrap_err(), Error::RankMismatch);
+
+    assert_eq!(t.clone().try_into_shape([3, !0]).unwrap(), view![[1, 2], [3, 4], [5, 6]]);
+    assert_eq!(t.clone().try_into_shape([5]).unwrap_err().1, Error::ShapeMismatch);
+    assert_eq!(t.clone().try_into_mapping::<(U2, U3)>().unwrap(), t);
+
+    let (u, err) = t.clone().try_into_mapping::<(U3, U2)>().unwrap_err();
+
+    assert_eq!((u, err), (t.clone(), Error::ShapeMismatch));
+    assert_eq!(t.clone().try_into_shape([2, 2]).unwrap_err().0, t);
+}
+
+#[test]
+fn test_windows() {
+    let t = Tensor::from_fn([4, 5], |i| (10 * i[0] + i[1]) as i32);
+
+    let w = t.windows([3, 2]);
+
+    assert_eq!(w.shape(), &(2, 4));
+    assert_eq!(w.clone().into_iter().nth(5).unwrap(), view![[11, 12], [21, 22], [31, 32]]);
+    assert_eq!(w.map(|x| x[[2, 1]]).eval(), view![[21, 22, 23, 24], [31, 32, 33, 34]]);
+
+    let v = t.view(step(.., -1), 1..);
+
+    assert_eq!(
+        v.windows((Const::<2>, Const::<4>)).eval()[[2, 0]],
+        view![[11, 12, 13, 14], [1, 2, 3, 4]]
+    );
+    assert_eq!(t.windows([5, 1]).eval().shape(), &(0, 5));
+    assert_eq!(view![1, 2, 3].windows([1]).map(|x| x[0]).eval(), view![1, 2, 3]);
+
+    let c = t.chunks_exact([2, 2]);
+
+    assert_eq!(c.shape(), &(2, 2));
+    assert_eq!(c.map(|x| x[[1, 0]]).eval(), view![[10, 12], [30, 32]]);
+    assert_eq!(t.chunks_exact([3, 6]).eval().shape(), &(1, 0));
+    assert_eq!(v.chunks_exact([2, 4]).eval()[[1, 0]], view![[11, 12, 13, 14], [1, 2, 3, 4]]);
+
+    let mut u = t.clone();
+
+    u.chunks_exact_mut([2, 2]).enumerate().for_each(|(i, mut x)| x.fill(i as i32));
+
+    assert_eq!(u, view![[0, 0, 1, 1, 4], [0, 0, 1, 1, 14], [2, 2, 3, 3, 24], [2, 2, 3, 3, 34]]);
+
+    let c = t.chunks([3, 2]);
+
+    assert_eq!(c.shape(), &(2, 3));
+    assert_eq!(c.map(|x| x.len()).eval(), view![[6, 6, 3], [2, 2, 1]]);
+    assert_eq!(
+        expr::zip(t.chunks([3, 2]), view![[0], [0]]).map(|(x, _)| x.len()).eval(),
+        view![[6, 6, 3], [2, 2, 1]]
+    );
+    assert_eq!(t.chunks([3, 2]).eval()[[1, 2]], view![[34]]);
+    assert_eq!(t.chunks([5, 6]).eval()[[0, 0]], t);
+    assert_eq!(t.chunks([2, 2]).into_iter().nth(5).unwrap(), view![[24], [34]]);
+    assert_eq!(v.chunks([3, 3]).eval()[[1, 1]], view![[4]]);
+    assert_eq!(Tensor::<i32, _>::from_elem([0, 3], 0).chunks([2, 2]).shape(), &(0, 2));
+
+    let mut u = t.clone();
+
+    u.chunks_mut([3, 2]).enumerate().for_each(|(i, mut x)| x.fill(i as i32));
+
+    assert_eq!(u, view![[0, 0, 1, 1, 2], [0, 0, 1, 1, 2], [0, 0, 1, 1, 2], [3, 3, 4, 4, 5]]);
+
+    let s = Tensor::<i32, ()>::from_elem((), 7);
+
+    assert_eq!(s.windows(()).map(|x| x[[]]).eval()[[]], 7);
+}